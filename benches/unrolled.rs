@@ -0,0 +1,61 @@
+//Compares UnrolledList with DoublyLinkedList, run with cargo bench --bench unrolled.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use lists_lib::list::*;
+use lists_lib::unrolled::*;
+
+const SIZE : usize = 10_000;
+const INSERTS : usize = 1_000;
+const ROUNDS : usize = 20;
+
+//Best time out of ROUNDS runs of f.
+fn measure<F : FnMut()>(mut f : F) -> Duration {
+    (0..ROUNDS).map(|_| {
+        let start = Instant::now();
+        f();
+        start.elapsed()
+    }).min().unwrap()
+}
+
+fn filled<L : List<u64> + Default>() -> L {
+    let mut list = L::default();
+    for i in 0..SIZE {
+        list.append(i as u64);
+    }
+    list
+}
+
+fn report(name : &str, dllist : Duration, unrolled : Duration) {
+    println!("{:<28} DoublyLinkedList {:>12?}   UnrolledList {:>12?}   x{:.1}",
+        name, dllist, unrolled, dllist.as_secs_f64() / unrolled.as_secs_f64());
+}
+
+fn main() {
+    let dllist : DoublyLinkedList<u64> = filled();
+    let unrolled : UnrolledList<u64> = filled();
+
+    report("iterate 10k",
+        measure(|| { black_box(dllist.iter().sum::<u64>()); }),
+        measure(|| { black_box(unrolled.iter().sum::<u64>()); }));
+
+    report("insert_at middle 1k",
+        measure(|| {
+            let mut list = dllist.clone();
+            for i in 0..INSERTS {
+                list.insert_at(i as u64, list.size() / 2);
+            }
+            black_box(list.size());
+        }),
+        measure(|| {
+            let mut list = unrolled.clone();
+            for i in 0..INSERTS {
+                list.insert_at(i as u64, list.size() / 2);
+            }
+            black_box(list.size());
+        }));
+
+    report("append 10k",
+        measure(|| { black_box(filled::<DoublyLinkedList<u64>>().size()); }),
+        measure(|| { black_box(filled::<UnrolledList<u64>>().size()); }));
+}
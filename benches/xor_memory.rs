@@ -0,0 +1,61 @@
+//Heap used by XorList and DoublyLinkedList, run with cargo bench --bench xor_memory.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use lists_lib::list::*;
+use lists_lib::xor_list::*;
+
+const SIZE : usize = 1_000_000;
+
+//Counts the bytes requested from the system allocator.
+struct Counting;
+
+static ALLOCATED : AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout : Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr : *mut u8, layout : Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL : Counting = Counting;
+
+//Bytes still allocated after building a list with f.
+fn measure<L, F : FnOnce() -> L>(f : F) -> (L, usize) {
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    let list = f();
+    (list, ALLOCATED.load(Ordering::Relaxed) - before)
+}
+
+fn report(name : &str, bytes : usize) {
+    println!("{:<18} {:>6.1} MB  {:>5.1} bytes per element", name, bytes as f64 / 1e6, bytes as f64 / SIZE as f64);
+}
+
+fn main() {
+    let (dllist, bytes) = measure(|| {
+        let mut list : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        for i in 0..SIZE {
+            list.push_back(i as u64);
+        }
+        list
+    });
+    report("DoublyLinkedList", bytes);
+    drop(dllist);
+
+    let (xor_list, bytes) = measure(|| {
+        let mut list : XorList<u64> = XorList::new();
+        for i in 0..SIZE {
+            list.push_back(i as u64);
+        }
+        list
+    });
+    report("XorList", bytes);
+    drop(xor_list);
+}
@@ -0,0 +1,174 @@
+use core::marker::PhantomData;
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::list::*;
+
+struct State<D> {
+    deque : D,
+    //Deque doesn't have a size, so it's counted here.
+    n : usize,
+    closed : bool,
+}
+
+/**
+* Deque shared between threads, consumers wait in pop_front until there is an element.
+*
+* It wraps any Deque that can be sent between threads, for example XorList or UnrolledList.
+* DoublyLinkedList can't be used because its nodes are Rc. With a capacity, producers wait in
+* push_back and push_front until there is room. close() wakes every waiting thread, after it
+* pushes fail and pops only return the elements that are left.
+*/
+pub struct BlockingDeque<T, D : Deque<T>> {
+    state : Mutex<State<D>>,
+    not_empty : Condvar,
+    not_full : Condvar,
+    capacity : Option<usize>,
+    //Only ties T to the struct, the elements live in the deque so they only need to be Send.
+    _values : PhantomData<fn() -> T>,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+impl<T, D : Deque<T>> BlockingDeque<T, D> {
+    //deque must be empty.
+    pub fn new(deque : D) -> BlockingDeque<T, D> {
+        BlockingDeque::build(deque, None)
+    }
+
+    //Producers wait while the deque holds capacity elements, deque must be empty.
+    pub fn with_capacity(deque : D, capacity : usize) -> BlockingDeque<T, D> {
+        BlockingDeque::build(deque, Some(capacity))
+    }
+
+    fn build(deque : D, capacity : Option<usize>) -> BlockingDeque<T, D> {
+        if !deque.empty() {
+            panic!("BlockingDeque needs an empty deque.");
+        }
+
+        BlockingDeque {
+            state : Mutex::new(State { deque, n : 0, closed : false }),
+            not_empty : Condvar::new(), not_full : Condvar::new(), capacity, _values : PhantomData,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State<D>> {
+        self.state.lock().unwrap()
+    }
+
+    pub fn size(&self) -> usize {
+        self.lock().n
+    }
+
+    pub fn empty(&self) -> bool {
+        self.lock().n == 0
+    }
+
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+
+    //Wakes every waiting thread, pushes fail from now on and pops return None once it's empty.
+    pub fn close(&self) {
+        self.lock().closed = true;
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+
+    /**
+    * @brief Pushes an element to the end, waiting for room if the deque is full.
+    *
+    * @return Result<(), T> Err with the element if the deque is closed.
+    */
+    pub fn push_back(&self, value : T) -> Result<(), T> {
+        self.push(value, true)
+    }
+
+    /**
+    * @brief Pushes an element to the front, waiting for room if the deque is full.
+    *
+    * @return Result<(), T> Err with the element if the deque is closed.
+    */
+    pub fn push_front(&self, value : T) -> Result<(), T> {
+        self.push(value, false)
+    }
+
+    fn push(&self, value : T, back : bool) -> Result<(), T> {
+        let mut state = self.lock();
+        while !state.closed && self.capacity.is_some_and(|capacity| state.n >= capacity) {
+            state = self.not_full.wait(state).unwrap();
+        }
+        if state.closed {
+            return Err(value);
+        }
+
+        if back { state.deque.push_back(value); } else { state.deque.push_front(value); }
+        state.n += 1;
+        drop(state);
+
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    /**
+    * @brief Pops the first element, waiting until there is one.
+    *
+    * @return Option<T> None if the deque is closed and empty.
+    */
+    pub fn pop_front(&self) -> Option<T> {
+        let mut state = self.lock();
+        while !state.closed && state.n == 0 {
+            state = self.not_empty.wait(state).unwrap();
+        }
+        self.take_front(state)
+    }
+
+    /**
+    * @brief Pops the first element, waiting at most timeout for one.
+    *
+    * @return Option<T> None if the time ran out or the deque is closed and empty.
+    */
+    pub fn pop_front_timeout(&self, timeout : Duration) -> Option<T> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.lock();
+        while !state.closed && state.n == 0 {
+            let now = Instant::now();
+            if now >= deadline {
+                return None;
+            }
+            state = self.not_empty.wait_timeout(state, deadline - now).unwrap().0;
+        }
+        self.take_front(state)
+    }
+
+    //Pops the first element without waiting, None if the deque is empty.
+    pub fn try_pop(&self) -> Option<T> {
+        let state = self.lock();
+        self.take_front(state)
+    }
+
+    fn take_front(&self, mut state : MutexGuard<'_, State<D>>) -> Option<T> {
+        if state.n == 0 {
+            return None;
+        }
+
+        let value = state.deque.pop_front();
+        state.n -= 1;
+        drop(state);
+
+        self.not_full.notify_one();
+        Some(value)
+    }
+}
+
+impl<T, D : Deque<T> + Default> Default for BlockingDeque<T, D> {
+    fn default() -> Self {
+        BlockingDeque::new(D::default())
+    }
+}
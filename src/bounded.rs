@@ -0,0 +1,159 @@
+use core::fmt;
+use alloc::boxed::Box;
+
+use crate::list::*;
+
+//What a full BoundedDeque does with a new element.
+pub enum OverflowPolicy<T> {
+    //Removes the element at the other end to make room.
+    DropOldest,
+    //Discards the new element.
+    DropNewest,
+    //Discards the new element, Deque::push_back and Deque::push_front panic.
+    Reject,
+    //Removes the element at the other end like DropOldest and hands it to the callback.
+    Callback(Box<dyn FnMut(T)>),
+}
+
+/**
+* DoublyLinkedList that holds at most a fixed number of elements, useful as a sliding window.
+*
+* Pushing to a full deque follows its OverflowPolicy. try_push_back and try_push_front give the
+* new element back when it was not stored. Every push and pop is O(1).
+*/
+pub struct BoundedDeque<T : Clone + fmt::Display + core::convert::From<T>> {
+    list : DoublyLinkedList<T>,
+    limit : usize,
+    policy : OverflowPolicy<T>,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+impl<T : Clone + fmt::Display + core::convert::From<T>> BoundedDeque<T> {
+    //A deque of at most limit elements that drops the oldest one when full.
+    pub fn with_capacity_limit(limit : usize) -> BoundedDeque<T> {
+        BoundedDeque::with_policy(limit, OverflowPolicy::DropOldest)
+    }
+
+    pub fn with_policy(limit : usize, policy : OverflowPolicy<T>) -> BoundedDeque<T> {
+        BoundedDeque {
+            list : DoublyLinkedList::new(), limit, policy,
+        }
+    }
+
+    pub fn capacity_limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn set_policy(&mut self, policy : OverflowPolicy<T>) {
+        self.policy = policy;
+    }
+
+    pub fn size(&self) -> usize {
+        self.list.size()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.list.size() >= self.limit
+    }
+
+    pub fn iter(&self) -> DoublyIter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        if self.list.size() == 0 { None } else { Some(self.list.value_at(0)) }
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        if self.list.size() == 0 { None } else { Some(self.list.value_at(self.list.size() - 1)) }
+    }
+
+    //Removes every element.
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+
+    /**
+    * @brief Pushes an element to the end, a full deque follows its policy.
+    *
+    * @return Result<(), T> Err with the element if it was not stored.
+    */
+    pub fn try_push_back(&mut self, value : T) -> Result<(), T> {
+        self.push(value, true)
+    }
+
+    /**
+    * @brief Pushes an element to the front, a full deque follows its policy.
+    *
+    * @return Result<(), T> Err with the element if it was not stored.
+    */
+    pub fn try_push_front(&mut self, value : T) -> Result<(), T> {
+        self.push(value, false)
+    }
+
+    fn push(&mut self, value : T, back : bool) -> Result<(), T> {
+        //With a limit of 0 there is nothing to evict, so no policy can store the element.
+        if self.limit == 0 {
+            return Err(value);
+        }
+        if self.list.size() >= self.limit {
+            if let OverflowPolicy::DropNewest | OverflowPolicy::Reject = self.policy {
+                return Err(value);
+            }
+        }
+
+        if back { self.list.push_back(value); } else { self.list.push_front(value); }
+
+        if self.list.size() > self.limit {
+            let evicted = if back { self.list.pop_front() } else { self.list.pop_back() };
+            if let OverflowPolicy::Callback(callback) = &mut self.policy {
+                callback(evicted);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Deque<T> for BoundedDeque<T>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    fn empty(&self) -> bool {
+        self.list.size() == 0
+    }
+
+    //Panics if the deque is full and its policy is Reject.
+    fn push_back(&mut self, value : T) -> &mut Self {
+        if self.try_push_back(value).is_err() {
+            if let OverflowPolicy::Reject = self.policy {panic!("Full deque at push_back function.")};
+        }
+        self
+    }
+
+    fn pop_back(&mut self) -> T {
+        self.list.pop_back()
+    }
+
+    //Panics if the deque is full and its policy is Reject.
+    fn push_front(&mut self, value : T) -> &mut Self {
+        if self.try_push_front(value).is_err() {
+            if let OverflowPolicy::Reject = self.policy {panic!("Full deque at push_front function.")};
+        }
+        self
+    }
+
+    fn pop_front(&mut self) -> T {
+        self.list.pop_front()
+    }
+}
+
+impl<T> fmt::Display for BoundedDeque<T>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.list)
+    }
+}
@@ -0,0 +1,311 @@
+use core::cell::{OnceCell, RefCell};
+use core::fmt;
+use alloc::rc::Rc;
+use alloc::{string::{String, ToString}, vec, vec::Vec};
+
+use crate::deque::*;
+use crate::list::*;
+
+/**
+* Persistent list with O(1) append (Okasaki's catenable list).
+*
+* The list is a tree, each node holds a non empty LinkedNode segment followed by a queue of
+* child lists. Appending pushes the right list to the queue of the left one. When the segment
+* of the root runs out, the first child becomes the root and the rest of the queue is linked to
+* it as a single suspended child, which is only flattened further when it is reached. The
+* suspensions are memoized, so every version sharing them pays for them once and uncons stays
+* O(1) amortized.
+*/
+#[derive(Clone)]
+pub struct CatenableList<T : Clone> {
+    root : Option<Rc<CatNode<T>>>,
+    n : usize,
+}
+
+struct CatNode<T : Clone> {
+    segment : Rc<LinkedNode<T>>,
+    children : BankersDeque<Rc<Child<T>>>,
+}
+
+//A child list, either already built or the suspended link of the children left in a queue.
+struct Child<T : Clone> {
+    n : usize,
+    list : OnceCell<CatenableList<T>>,
+    suspended : RefCell<Option<BankersDeque<Rc<Child<T>>>>>,
+}
+
+pub struct Iter<'a, T : Clone> {
+    segment : Option<crate::list::Iter<'a, T>>,
+    stack : Vec<&'a CatNode<T>>,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+impl<T : Clone> Child<T> {
+    fn built(list : CatenableList<T>) -> Rc<Child<T>> {
+        Rc::from(Child { n : list.n, list : OnceCell::from(list), suspended : RefCell::new(None) })
+    }
+
+    //children must not be empty, n is the number of elements they hold.
+    fn suspended(children : BankersDeque<Rc<Child<T>>>, n : usize) -> Rc<Child<T>> {
+        Rc::from(Child { n, list : OnceCell::new(), suspended : RefCell::new(Some(children)) })
+    }
+
+    /**
+    * @brief The list of the child, linking its children if it was suspended.
+    *
+    * Linking only needs the first of the children built, which may be suspended too, so the
+    * ones that are needed first are kept in a stack instead of the call stack.
+    *
+    * @return &CatenableList<T> The list, every clone of the child shares it.
+    */
+    fn force(self : &Rc<Self>) -> &CatenableList<T> {
+        let mut stack = vec![self.clone()];
+        while let Some(child) = stack.last() {
+            if child.list.get().is_some() {
+                stack.pop();
+                continue;
+            }
+
+            let (first, _) = child.suspended.borrow().as_ref().unwrap().pop_front();
+            if first.list.get().is_none() {
+                stack.push(first);
+                continue;
+            }
+
+            let child = stack.pop().unwrap();
+            let list = child.link_all();
+            let _ = child.list.set(list);
+        }
+        self.list.get().unwrap()
+    }
+
+    //The first child with the rest linked to it as a new suspended child, O(1) amortized.
+    fn link_all(&self) -> CatenableList<T> {
+        let children = self.suspended.borrow_mut().take().unwrap();
+        let (first, rest) = children.pop_front();
+        let first = first.list.get().unwrap();
+
+        if rest.empty() {
+            first.clone()
+        } else {
+            CatenableList::link(first, Child::suspended(rest, self.n - first.n))
+        }
+    }
+}
+
+impl<T : Clone> CatenableList<T> {
+    pub fn new() -> CatenableList<T> {
+        CatenableList { root : None, n : 0 }
+    }
+
+    /**
+    * @brief Wraps a LinkedNode list without copying it.
+    *
+    * This method is implemented in O(n) for time, only to count the elements, and O(1) for memory.
+    *
+    * @return Self A catenable list with the same elements.
+    */
+    pub fn from_list(list : Rc<LinkedNode<T>>) -> CatenableList<T> {
+        if list.is_empty() {
+            return CatenableList::new();
+        }
+
+        let n = list.iter().count();
+        CatenableList {
+            root : Some(Rc::from(CatNode { segment : list, children : BankersDeque::new() })),
+            n,
+        }
+    }
+
+    pub fn to_list(&self) -> Rc<LinkedNode<T>> {
+        LinkedNode::prepend_all(self.iter().cloned().collect(), &LinkedNode::nil())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { segment : None, stack : self.root.as_deref().into_iter().collect() }
+    }
+
+    /**
+    * @brief Splits the list into its first element and the rest of the list.
+    *
+    * This method is implemented in O(1) amortized for time and memory.
+    *
+    * @return Option<(&T, Self)> None if the list is empty.
+    */
+    pub fn uncons(&self) -> Option<(&T, CatenableList<T>)> {
+        let node = self.root.as_ref()?;
+        let (head, segment) = node.segment.uncons().unwrap();
+
+        let tail = if segment.is_empty() {
+            if node.children.empty() {
+                CatenableList::new()
+            } else {
+                Child::suspended(node.children.clone(), self.n - 1).force().clone()
+            }
+        } else {
+            CatenableList {
+                root : Some(Rc::from(CatNode { segment, children : node.children.clone() })),
+                n : self.n - 1,
+            }
+        };
+
+        Some((head, tail))
+    }
+
+    pub fn head(&self) -> Option<&T> {
+        self.root.as_ref().map(|node| node.segment.value())
+    }
+
+    /**
+    * @brief The list without its first count elements.
+    *
+    * @return Self The suffix of the list, empty if count is bigger than the list.
+    */
+    pub fn skip(&self, count : usize) -> CatenableList<T> {
+        let mut list = self.clone();
+        for _ in 0..count {
+            match list.uncons() {
+                Some((_, tail)) => list = tail,
+                None => break,
+            }
+        }
+        list
+    }
+
+    //right becomes the last child of left, left must not be empty.
+    fn link(left : &CatenableList<T>, right : Rc<Child<T>>) -> CatenableList<T> {
+        let node = left.root.as_ref().unwrap();
+        CatenableList {
+            root : Some(Rc::from(CatNode {
+                segment : node.segment.clone(),
+                children : node.children.push_back(right.clone()),
+            })),
+            n : left.n + right.n,
+        }
+    }
+
+    //Appends right to left, any of them may be empty.
+    fn concat(left : &CatenableList<T>, right : CatenableList<T>) -> CatenableList<T> {
+        if left.is_empty() {
+            return right;
+        }
+        if right.is_empty() {
+            return left.clone();
+        }
+        CatenableList::link(left, Child::built(right))
+    }
+}
+
+impl<'a, T : Clone> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some(value) = self.segment.as_mut().and_then(|segment| segment.next()) {
+                return Some(value);
+            }
+
+            let node = self.stack.pop()?;
+            self.segment = Some(node.segment.iter());
+            let children : Vec<&'a CatNode<T>> = node.children.iter()
+                .filter_map(|child| child.force().root.as_deref())
+                .collect();
+            self.stack.extend(children.into_iter().rev());
+        }
+    }
+}
+
+//Lists built by linking thousands of fragments are deep trees, so they are dropped with an explicit stack.
+impl<T : Clone> Drop for CatenableList<T> {
+    fn drop(&mut self) {
+        let mut stack : Vec<Rc<CatNode<T>>> = self.root.take().into_iter().collect();
+
+        while let Some(node) = stack.pop() {
+            let mut queues = match Rc::try_unwrap(node) {
+                Ok(node) => vec![node.children],
+                Err(_) => continue,
+            };
+
+            //Taking the children out doesn't evaluate the rotations of the queues, so dropping stays cheap.
+            while let Some(queue) = queues.pop() {
+                for child in queue.into_unshared() {
+                    let Ok(child) = Rc::try_unwrap(child) else { continue };
+                    if let Some(mut list) = child.list.into_inner() {
+                        stack.extend(list.root.take());
+                    }
+                    queues.extend(child.suspended.into_inner());
+                }
+            }
+        }
+    }
+}
+
+impl<T : Clone> Default for CatenableList<T> {
+    fn default() -> Self {
+        CatenableList::new()
+    }
+}
+
+impl<T> fmt::Display for CatenableList<T>
+    where T : fmt::Display + Clone
+{
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        let values : Vec<String> = self.iter().map(|value| value.to_string()).collect();
+        write!(f, "[{}]", values.join(","))
+    }
+}
+
+impl<T> InmutList<T> for CatenableList<T>
+    where T : Clone
+{
+    //O(1) for time and memory.
+    fn append(list : Self, other_list : Self) -> Self {
+        CatenableList::concat(&list, other_list)
+    }
+
+    fn insert_at(list : Self, insert_list : Self, index : usize) -> Self {
+        if index > list.n {
+            panic!("Invalid index at function insert_at.");
+        }
+
+        let prefix = LinkedNode::prepend_all(list.iter().take(index).cloned().collect(), &LinkedNode::nil());
+        let rest = CatenableList::concat(&insert_list, list.skip(index));
+        CatenableList::concat(&CatenableList::from_list(prefix), rest)
+    }
+
+    fn remove_at(list : Self, index : usize, count : usize) -> Self {
+        if count == 0 {
+            return list;
+        }
+        if index + count > list.n {
+            panic!("Invalid count or index at remove_at function.");
+        }
+
+        let prefix = LinkedNode::prepend_all(list.iter().take(index).cloned().collect(), &LinkedNode::nil());
+        CatenableList::concat(&CatenableList::from_list(prefix), list.skip(index + count))
+    }
+
+    fn value_at(list : Self, index : usize) -> T {
+        match list.iter().nth(index) {
+            Some(value) => value.clone(),
+            None => panic!("Invalid index at value_at function."),
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.n
+    }
+
+    fn reverse(&self) -> Self {
+        let mut values : Vec<T> = self.iter().cloned().collect();
+        values.reverse();
+        CatenableList::from_list(LinkedNode::prepend_all(values, &LinkedNode::nil()))
+    }
+}
@@ -0,0 +1,382 @@
+use core::fmt;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, Weak};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::list::SharedList;
+
+struct Links<T> {
+    //None for the head and tail sentinels.
+    value : Option<T>,
+    next : Option<Arc<Node<T>>>,
+    prev : Weak<Node<T>>,
+    removed : bool,
+    //True while a Locked owns the node, the mutex itself is only held for a few instructions.
+    held : bool,
+    //Threads sleeping until the node is released, so it's only signalled when someone waits.
+    waiting : usize,
+}
+
+struct Node<T> {
+    links : Mutex<Links<T>>,
+    released : Condvar,
+}
+
+//Owns the lock of a node until it's dropped, the Arc keeps the node alive meanwhile.
+struct Locked<T> {
+    node : Arc<Node<T>>,
+}
+
+/**
+* Doubly linked list shared between threads, every node has its own lock.
+*
+* Walks lock hand over hand, always from head towards tail, so threads working on different
+* regions don't wait for each other and can't deadlock. A node is locked by setting a flag under
+* its mutex, so the lock can outlive the borrow of the mutex and be handed over to the next node.
+* Operations that start from a node and need the one before it read its prev link, lock that node
+* and check it still points to the node, trying again otherwise. snapshot() locks every node at once to copy a consistent state.
+*/
+pub struct ConcurrentList<T> {
+    head : Arc<Node<T>>,
+    tail : Arc<Node<T>>,
+    n : AtomicUsize,
+}
+
+//Handle to an element, returned by the pushes and used by remove_node and insert_before.
+pub struct NodeRef<T> {
+    node : Arc<Node<T>>,
+}
+
+//Values of the list at the moment snapshot() was called.
+pub struct Snapshot<T> {
+    values : std::vec::IntoIter<T>,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+impl<T> Node<T> {
+    fn new(value : Option<T>) -> Arc<Node<T>> {
+        Arc::new(Node {
+            links : Mutex::new(Links { value, next : None, prev : Weak::new(), removed : false, held : false, waiting : 0 }),
+            released : Condvar::new(),
+        })
+    }
+}
+
+impl<T> Locked<T> {
+    //Waits until no other Locked owns node.
+    fn new(node : Arc<Node<T>>) -> Locked<T> {
+        let mut links = node.links.lock().unwrap();
+        while links.held {
+            links.waiting += 1;
+            links = node.released.wait(links).unwrap();
+            links.waiting -= 1;
+        }
+        links.held = true;
+        drop(links);
+
+        Locked { node }
+    }
+
+    //Nobody else changes the links of a locked node, so this never waits for long.
+    fn links(&self) -> MutexGuard<'_, Links<T>> {
+        self.node.links.lock().unwrap()
+    }
+
+    fn next(&self) -> Arc<Node<T>> {
+        self.links().next.clone().unwrap()
+    }
+
+    fn points_to(&self, node : &Arc<Node<T>>) -> bool {
+        self.links().next.as_ref().is_some_and(|next| Arc::ptr_eq(next, node))
+    }
+}
+
+impl<T> Drop for Locked<T> {
+    fn drop(&mut self) {
+        let mut links = self.links();
+        links.held = false;
+        if links.waiting > 0 {
+            self.node.released.notify_one();
+        }
+    }
+}
+
+impl<T> ConcurrentList<T> {
+    pub fn new() -> ConcurrentList<T> {
+        let head = Node::new(None);
+        let tail = Node::new(None);
+        head.links.lock().unwrap().next = Some(tail.clone());
+        tail.links.lock().unwrap().prev = Arc::downgrade(&head);
+
+        ConcurrentList { head, tail, n : AtomicUsize::new(0) }
+    }
+
+    //Only a guess while other threads are working.
+    pub fn size(&self) -> usize {
+        self.n.load(Ordering::SeqCst)
+    }
+
+    pub fn empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    //Puts a new node between two locked neighbours.
+    fn link(&self, pred : &Locked<T>, succ : &Locked<T>, value : T) -> NodeRef<T> {
+        let node = Node::new(Some(value));
+        {
+            let mut links = node.links.lock().unwrap();
+            links.prev = Arc::downgrade(&pred.node);
+            links.next = Some(succ.node.clone());
+        }
+        pred.links().next = Some(node.clone());
+        succ.links().prev = Arc::downgrade(&node);
+        self.n.fetch_add(1, Ordering::SeqCst);
+
+        NodeRef { node }
+    }
+
+    //Takes target out from between its two locked neighbours.
+    fn unlink(&self, pred : &Locked<T>, target : &Locked<T>, succ : &Locked<T>) {
+        pred.links().next = Some(succ.node.clone());
+        succ.links().prev = Arc::downgrade(&pred.node);
+        let mut links = target.links();
+        links.next = None;
+        links.prev = Weak::new();
+        links.removed = true;
+        self.n.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    //Locks the node before index and the one at index, None if index is past the tail.
+    fn lock_at(&self, index : usize) -> Option<(Locked<T>, Locked<T>)> {
+        let mut pred = Locked::new(self.head.clone());
+        for _ in 0..index {
+            let next = pred.next();
+            if Arc::ptr_eq(&next, &self.tail) {
+                return None;
+            }
+            pred = Locked::new(next);
+        }
+        let current = Locked::new(pred.next());
+        Some((pred, current))
+    }
+
+    //Locks the node before node and node itself, None if node was removed.
+    fn lock_with_pred(&self, node : &Arc<Node<T>>) -> Option<(Locked<T>, Locked<T>)> {
+        loop {
+            let pred = {
+                let links = node.links.lock().unwrap();
+                if links.removed { return None; }
+                links.prev.upgrade()
+            };
+            //The node before was removed meanwhile, its remover updates prev.
+            let Some(pred) = pred else { continue };
+
+            let pred = Locked::new(pred);
+            if pred.points_to(node) {
+                return Some((pred, Locked::new(node.clone())));
+            }
+        }
+    }
+
+    /**
+    * @brief Inserts an element so it ends up at the specified index.
+    *
+    * Only the nodes up to index are locked, and never more than two at a time.
+    *
+    * @return u8 0 on success. Panics if index is bigger than the size.
+    */
+    pub fn insert_at(&self, value : T, index : usize) -> u8 {
+        match self.lock_at(index) {
+            Some((pred, succ)) => { self.link(&pred, &succ, value); 0 }
+            None => panic!("Index out of bounds in function insert_at."),
+        }
+    }
+
+    pub fn append(&self, value : T) -> u8 {
+        self.push_back(value);
+        0
+    }
+
+    //Inserts an element to the beginning, O(1).
+    pub fn push_front(&self, value : T) -> NodeRef<T> {
+        let pred = Locked::new(self.head.clone());
+        let succ = Locked::new(pred.next());
+        self.link(&pred, &succ, value)
+    }
+
+    //Inserts an element to the end, O(1) unless the last node is being removed.
+    pub fn push_back(&self, value : T) -> NodeRef<T> {
+        let (pred, succ) = self.lock_with_pred(&self.tail).unwrap();
+        self.link(&pred, &succ, value)
+    }
+
+    /**
+    * @brief Inserts an element right before node.
+    *
+    * @return Option<NodeRef<T>> The new element, None if node was already removed.
+    */
+    pub fn insert_before(&self, node : &NodeRef<T>, value : T) -> Option<NodeRef<T>> {
+        let (pred, succ) = self.lock_with_pred(&node.node)?;
+        Some(self.link(&pred, &succ, value))
+    }
+
+    /**
+    * @brief Removes the element at index.
+    *
+    * @return u8 0 on success, 1 if the list is empty and 2 if index is out of bounds.
+    */
+    pub fn remove_at(&self, index : usize) -> u8 {
+        let (pred, target) = match self.lock_at(index) {
+            Some(pair) if !Arc::ptr_eq(&pair.1.node, &self.tail) => pair,
+            _ => return if self.empty() { 1 } else { 2 },
+        };
+        let succ = Locked::new(target.next());
+        self.unlink(&pred, &target, &succ);
+        0
+    }
+
+    /**
+    * @brief Removes the element of node, O(1) unless its neighbours are changing.
+    *
+    * @return bool true if node was still in the list.
+    */
+    pub fn remove_node(&self, node : &NodeRef<T>) -> bool {
+        let Some((pred, target)) = self.lock_with_pred(&node.node) else { return false };
+        let succ = Locked::new(target.next());
+        self.unlink(&pred, &target, &succ);
+        true
+    }
+
+    //Calls f with the element at index while it's locked, None if index is out of bounds.
+    pub fn update<R, F : FnOnce(&mut T) -> R>(&self, index : usize, f : F) -> Option<R> {
+        let (_, current) = self.lock_at(index)?;
+        let mut links = current.links();
+        links.value.as_mut().map(f)
+    }
+
+    /**
+    * @brief Calls f with every element in order, locking hand over hand.
+    *
+    * Elements inserted or removed behind the walk while it runs are not seen.
+    */
+    pub fn for_each<F : FnMut(&T)>(&self, mut f : F) {
+        let mut current = Locked::new(self.head.clone());
+        loop {
+            let next = Locked::new(current.next());
+            if Arc::ptr_eq(&next.node, &self.tail) { break; }
+            if let Some(value) = next.links().value.as_ref() { f(value); }
+            current = next;
+        }
+    }
+
+    //Removes every element.
+    pub fn clear(&self) {
+        while self.remove_at(0) == 0 {}
+    }
+}
+
+impl<T : Clone> ConcurrentList<T> {
+    //A copy of the element at index, None if index is out of bounds.
+    pub fn value_at(&self, index : usize) -> Option<T> {
+        let (_, current) = self.lock_at(index)?;
+        let value = current.links().value.clone();
+        value
+    }
+
+    /**
+    * @brief Copies every element while all the nodes are locked.
+    *
+    * The result is the list as it was at a single moment. Writers wait until the copy is done.
+    */
+    pub fn snapshot(&self) -> Snapshot<T> {
+        let mut locked = Vec::new();
+        let mut values = Vec::new();
+
+        locked.push(Locked::new(self.head.clone()));
+        loop {
+            let next = locked.last().unwrap().next();
+            if Arc::ptr_eq(&next, &self.tail) { break; }
+            let node = Locked::new(next);
+            values.push(node.links().value.clone().unwrap());
+            locked.push(node);
+        }
+        drop(locked);
+
+        Snapshot { values : values.into_iter() }
+    }
+}
+
+impl<T> NodeRef<T> {
+    pub fn is_removed(&self) -> bool {
+        self.node.links.lock().unwrap().removed
+    }
+}
+
+impl<T : Clone> NodeRef<T> {
+    //A copy of the element, None if it was removed.
+    pub fn value(&self) -> Option<T> {
+        let links = self.node.links.lock().unwrap();
+        if links.removed { None } else { links.value.clone() }
+    }
+}
+
+impl<T : Clone> SharedList<T> for ConcurrentList<T> {
+    fn append(&self, value : T) -> u8 {
+        ConcurrentList::append(self, value)
+    }
+
+    fn insert_at(&self, value : T, index : usize) -> u8 {
+        ConcurrentList::insert_at(self, value, index)
+    }
+
+    fn remove_at(&self, index : usize) -> u8 {
+        ConcurrentList::remove_at(self, index)
+    }
+
+    fn value_at(&self, index : usize) -> Option<T> {
+        ConcurrentList::value_at(self, index)
+    }
+
+    fn size(&self) -> usize {
+        ConcurrentList::size(self)
+    }
+}
+
+impl<T> Default for ConcurrentList<T> {
+    fn default() -> Self {
+        ConcurrentList::new()
+    }
+}
+
+//Long lists would overflow the stack if the nodes were dropped recursively.
+impl<T> Drop for ConcurrentList<T> {
+    fn drop(&mut self) {
+        let mut next = self.head.links.lock().unwrap().next.take();
+        while let Some(node) = next {
+            next = node.links.lock().unwrap().next.take();
+        }
+    }
+}
+
+impl<T> Iterator for Snapshot<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.values.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<T> fmt::Display for ConcurrentList<T>
+    where T : fmt::Display + Clone
+{
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        let values : Vec<String> = self.snapshot().map(|value| value.to_string()).collect();
+        write!(f, "[{}]", values.join(","))
+    }
+}
@@ -0,0 +1,381 @@
+use core::fmt;
+use core::cell::{OnceCell, RefCell};
+use alloc::rc::Rc;
+use alloc::{string::{String, ToString}, vec, vec::Vec};
+
+use crate::list::*;
+
+//Neither list may have more than BALANCE times the elements of the other one, plus one.
+const BALANCE : usize = 3;
+
+//An evaluated cell, None is the end of the stream.
+type Cell<T> = Option<(T, Stream<T>)>;
+
+//Suspended operations of a rotation, they are stored as data so T doesn't need to be 'static.
+enum Suspension<T : Clone> {
+    //The first n elements of the stream.
+    Take(usize, Stream<T>),
+    //The first stream followed by the second one, one cell at a time.
+    Append(Stream<T>, Stream<T>),
+    //The stream without its first n elements, reversed. Evaluated all at once.
+    ReverseDrop(usize, Stream<T>),
+}
+
+struct StreamCell<T : Clone> {
+    value : OnceCell<Cell<T>>,
+    suspension : RefCell<Option<Suspension<T>>>,
+}
+
+/**
+* Lazy list whose cells are evaluated once and shared by every version that holds them.
+*
+* The deque uses it instead of LinkedNode because a LinkedNode is always built with its next
+* node, so a suspension could only wrap a whole Rc<LinkedNode<T>> and forcing it would build every
+* moved node at once. Here each cell of a rotation is suspended on its own and pops pay for it one
+* cell at a time, which is what keeps old versions cheap to reuse.
+*/
+#[derive(Clone)]
+struct Stream<T : Clone> {
+    cell : Rc<StreamCell<T>>,
+}
+
+/**
+* Persistent double-ended queue made of two lazy lists (Okasaki's banker's deque).
+*
+* The front list holds the first elements in order and the back list holds the last elements
+* in reverse order. When one of them grows too big compared to the other, half of its elements
+* are moved. The move is suspended and its cells are memoized, so they are only computed once no
+* matter how many versions use them, which keeps push and pop O(1) amortized at both ends even
+* when an old version is used again. from_list and to_list convert from and to LinkedNode.
+*/
+#[derive(Clone)]
+pub struct BankersDeque<T : Clone> {
+    front : Stream<T>,
+    front_len : usize,
+    back : Stream<T>,
+    back_len : usize,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+impl<T : Clone> Stream<T> {
+    fn evaluated(cell : Cell<T>) -> Stream<T> {
+        Stream {
+            cell : Rc::from(StreamCell { value : OnceCell::from(cell), suspension : RefCell::new(None) }),
+        }
+    }
+
+    fn suspended(suspension : Suspension<T>) -> Stream<T> {
+        Stream {
+            cell : Rc::from(StreamCell { value : OnceCell::new(), suspension : RefCell::new(Some(suspension)) }),
+        }
+    }
+
+    fn nil() -> Stream<T> {
+        Stream::evaluated(None)
+    }
+
+    fn cons(value : T, tail : Stream<T>) -> Stream<T> {
+        Stream::evaluated(Some((value, tail)))
+    }
+
+    fn from_values<I : DoubleEndedIterator<Item = T>>(values : I) -> Stream<T> {
+        values.rev().fold(Stream::nil(), |tail, value| Stream::cons(value, tail))
+    }
+
+    /**
+    * @brief Evaluates the first cell, every clone shares the result.
+    *
+    * Rotations nest suspensions inside each other, so the ones that are needed first are kept
+    * in a stack instead of the call stack.
+    *
+    * @return &Cell<T> The first element and the rest of the stream, None if it's empty.
+    */
+    fn force(&self) -> &Cell<T> {
+        let mut stack = vec![self.clone()];
+        while let Some(stream) = stack.last() {
+            if stream.cell.value.get().is_some() {
+                stack.pop();
+                continue;
+            }
+            match stream.needs() {
+                Some(inner) => stack.push(inner),
+                None => {
+                    let stream = stack.pop().unwrap();
+                    let cell = stream.evaluate();
+                    let _ = stream.cell.value.set(cell);
+                }
+            }
+        }
+        self.cell.value.get().unwrap()
+    }
+
+    //The stream that has to be evaluated before this one, None if this one can be evaluated now.
+    fn needs(&self) -> Option<Stream<T>> {
+        let pending = |stream : &Stream<T>| stream.cell.value.get().is_none().then(|| stream.clone());
+
+        match self.cell.suspension.borrow().as_ref().unwrap() {
+            Suspension::Take(0, _) | Suspension::ReverseDrop(..) => None,
+            Suspension::Take(_, stream) => pending(stream),
+            Suspension::Append(first, second) => match first.cell.value.get() {
+                None => Some(first.clone()),
+                Some(None) => pending(second),
+                Some(Some(_)) => None,
+            },
+        }
+    }
+
+    //Only called once needs() returns None, so the inner streams are already evaluated.
+    fn evaluate(&self) -> Cell<T> {
+        let suspension = self.cell.suspension.borrow_mut().take().unwrap();
+        match suspension {
+            Suspension::Take(0, _) => None,
+            Suspension::Take(n, stream) => {
+                let (value, tail) = stream.force().as_ref()?;
+                Some((value.clone(), Stream::suspended(Suspension::Take(n - 1, tail.clone()))))
+            }
+            Suspension::Append(first, second) => match first.force() {
+                Some((value, tail)) => Some((value.clone(), Stream::suspended(Suspension::Append(tail.clone(), second)))),
+                None => second.force().clone(),
+            },
+            Suspension::ReverseDrop(n, stream) => {
+                let reversed = stream.iter().skip(n).fold(Stream::nil(), |tail, value| Stream::cons(value.clone(), tail));
+                reversed.force().clone()
+            }
+        }
+    }
+
+    //Moves the element and the streams held by cell out if nothing else shares cell.
+    fn take_inner(cell : &mut Rc<StreamCell<T>>, streams : &mut Vec<Stream<T>>, values : &mut Vec<T>) {
+        let Some(cell) = Rc::get_mut(cell) else { return };
+
+        if let Some(Some((value, tail))) = cell.value.take() {
+            values.push(value);
+            streams.push(tail);
+        }
+        match cell.suspension.get_mut().take() {
+            Some(Suspension::Take(_, stream)) | Some(Suspension::ReverseDrop(_, stream)) => streams.push(stream),
+            Some(Suspension::Append(first, second)) => { streams.push(first); streams.push(second); }
+            None => {}
+        }
+    }
+
+    //Moves out every element only this stream holds, nothing is evaluated.
+    fn take_values(&mut self, values : &mut Vec<T>) {
+        let mut streams : Vec<Stream<T>> = Vec::new();
+        Stream::take_inner(&mut self.cell, &mut streams, values);
+
+        //Each popped stream is dropped after its inner streams were moved out, so that drop is shallow.
+        while let Some(mut stream) = streams.pop() {
+            Stream::take_inner(&mut stream.cell, &mut streams, values);
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &T> {
+        let mut current = self;
+        core::iter::from_fn(move || {
+            let (value, tail) = current.force().as_ref()?;
+            current = tail;
+            Some(value)
+        })
+    }
+}
+
+//Long streams would overflow the stack if the cells were dropped recursively.
+impl<T : Clone> Drop for Stream<T> {
+    fn drop(&mut self) {
+        let mut values = Vec::new();
+        self.take_values(&mut values);
+    }
+}
+
+impl<T : Clone> BankersDeque<T> {
+    pub fn new() -> BankersDeque<T> {
+        BankersDeque {
+            front : Stream::nil(), front_len : 0, back : Stream::nil(), back_len : 0,
+        }
+    }
+
+    pub fn from_list(list : &LinkedNode<T>) -> BankersDeque<T> {
+        let values : Vec<T> = list.iter().cloned().collect();
+        BankersDeque::from_values(values)
+    }
+
+    pub fn to_list(&self) -> Rc<LinkedNode<T>> {
+        LinkedNode::prepend_all(self.iter().cloned().collect(), &LinkedNode::nil())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let back : Vec<&T> = self.back.iter().collect();
+        self.front.iter().chain(back.into_iter().rev())
+    }
+
+    /**
+    * @brief Takes out the elements no other version shares, without evaluating any suspension.
+    *
+    * Lets structures nested through their elements be dropped with an explicit stack.
+    *
+    * @return Vec<T> The elements in no particular order.
+    */
+    pub(crate) fn into_unshared(mut self) -> Vec<T> {
+        let mut values = Vec::new();
+        self.front.take_values(&mut values);
+        self.back.take_values(&mut values);
+        values
+    }
+
+    fn from_values(values : Vec<T>) -> BankersDeque<T> {
+        let front_len = values.len();
+        BankersDeque::check(Stream::from_values(values.into_iter()), front_len, Stream::nil(), 0)
+    }
+
+    //Suspends the move of half of the elements if one list got too big, nothing is evaluated here.
+    fn check(front : Stream<T>, front_len : usize, back : Stream<T>, back_len : usize) -> BankersDeque<T> {
+        let total = front_len + back_len;
+
+        if front_len > BALANCE * back_len + 1 {
+            let new_front_len = total / 2;
+            BankersDeque {
+                back : Stream::suspended(Suspension::Append(back,
+                    Stream::suspended(Suspension::ReverseDrop(new_front_len, front.clone())))),
+                back_len : total - new_front_len,
+                front : Stream::suspended(Suspension::Take(new_front_len, front)),
+                front_len : new_front_len,
+            }
+        } else if back_len > BALANCE * front_len + 1 {
+            let new_back_len = total / 2;
+            BankersDeque {
+                front : Stream::suspended(Suspension::Append(front,
+                    Stream::suspended(Suspension::ReverseDrop(new_back_len, back.clone())))),
+                front_len : total - new_back_len,
+                back : Stream::suspended(Suspension::Take(new_back_len, back)),
+                back_len : new_back_len,
+            }
+        } else {
+            BankersDeque { front, front_len, back, back_len }
+        }
+    }
+}
+
+impl<T : Clone> Default for BankersDeque<T> {
+    fn default() -> Self {
+        BankersDeque::new()
+    }
+}
+
+impl<T> PersistentDeque<T> for BankersDeque<T>
+    where T : Clone
+{
+    fn empty(&self) -> bool {
+        self.front_len + self.back_len == 0
+    }
+
+    fn push_back(&self, value : T) -> Self {
+        BankersDeque::check(self.front.clone(), self.front_len,
+            Stream::cons(value, self.back.clone()), self.back_len + 1)
+    }
+
+    fn pop_back(&self) -> (T, Self) {
+        //The balance invariant guarantees the back can only be empty if there is at most one element.
+        match self.back.force() {
+            Some((value, back)) => (value.clone(),
+                BankersDeque::check(self.front.clone(), self.front_len, back.clone(), self.back_len - 1)),
+            None => match self.front.force() {
+                Some((value, _)) => (value.clone(), BankersDeque::new()),
+                None => panic!("pop_back called on empty deque."),
+            }
+        }
+    }
+
+    fn push_front(&self, value : T) -> Self {
+        BankersDeque::check(Stream::cons(value, self.front.clone()),
+            self.front_len + 1, self.back.clone(), self.back_len)
+    }
+
+    fn pop_front(&self) -> (T, Self) {
+        match self.front.force() {
+            Some((value, front)) => (value.clone(),
+                BankersDeque::check(front.clone(), self.front_len - 1, self.back.clone(), self.back_len)),
+            None => match self.back.force() {
+                Some((value, _)) => (value.clone(), BankersDeque::new()),
+                None => panic!("pop_front called on empty deque."),
+            }
+        }
+    }
+}
+
+impl<T> InmutList<T> for BankersDeque<T>
+    where T : Clone
+{
+    fn append(list : Self, other_list : Self) -> Self {
+        other_list.iter().fold(list, |acc, value| acc.push_back(value.clone()))
+    }
+
+    fn insert_at(list : Self, insert_list : Self, index : usize) -> Self {
+        if index > list.size() {
+            panic!("Invalid index at function insert_at.");
+        }
+
+        let values : Vec<T> = list.iter().take(index)
+            .chain(insert_list.iter())
+            .chain(list.iter().skip(index))
+            .cloned().collect();
+        BankersDeque::from_values(values)
+    }
+
+    fn remove_at(list : Self, index : usize, count : usize) -> Self {
+        if count == 0 {
+            return list;
+        }
+        if index + count > list.size() {
+            panic!("Invalid count or index at remove_at function.");
+        }
+
+        let values : Vec<T> = list.iter().take(index)
+            .chain(list.iter().skip(index + count))
+            .cloned().collect();
+        BankersDeque::from_values(values)
+    }
+
+    //O(n) for time and O(1) for memory, it walks the list that holds index forcing its cells.
+    fn value_at(list : Self, index : usize) -> T {
+        if index < list.front_len {
+            list.front.iter().nth(index).unwrap().clone()
+        } else if index < list.size() {
+            list.back.iter().nth(list.size() - 1 - index).unwrap().clone()
+        } else {
+            panic!("Invalid index at value_at function.");
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.front_len + self.back_len
+    }
+
+    //O(1), both lists just swap places.
+    fn reverse(&self) -> Self {
+        BankersDeque {
+            front : self.back.clone(), front_len : self.back_len,
+            back : self.front.clone(), back_len : self.front_len,
+        }
+    }
+}
+
+impl<T> fmt::Debug for BankersDeque<T>
+    where T : fmt::Debug + Clone
+{
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> fmt::Display for BankersDeque<T>
+    where T : fmt::Display + Clone
+{
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        let values : Vec<String> = self.iter().map(|value| value.to_string()).collect();
+        write!(f, "[{}]", values.join(","))
+    }
+}
@@ -0,0 +1,471 @@
+use core::fmt;
+use alloc::rc::Rc;
+use alloc::{string::{String, ToString}, vec::Vec, vec};
+
+use crate::list::*;
+
+//Leaves hold the elements, nodes hold 2 or 3 items of the level below and cache their size.
+#[derive(Debug)]
+enum Item<T> {
+    Leaf(T),
+    Node(usize, Vec<Rc<Item<T>>>),
+}
+
+//Digits hold between 1 and 4 items. The middle tree of a Deep holds nodes one level up.
+#[derive(Debug)]
+enum Tree<T> {
+    Empty,
+    Single(Rc<Item<T>>),
+    Deep(usize, Digit<T>, Rc<Tree<T>>, Digit<T>),
+}
+
+type Digit<T> = Vec<Rc<Item<T>>>;
+//Trees at both sides of an item, or a digit split the same way.
+type Split<T> = (Rc<Tree<T>>, Rc<Item<T>>, Rc<Tree<T>>);
+type DigitSplit<T> = (Digit<T>, Rc<Item<T>>, Digit<T>);
+//An item at one end and the rest of the tree.
+type View<T> = (Rc<Item<T>>, Rc<Tree<T>>);
+
+/**
+* Persistent sequence based on a 2-3 finger tree (Hinze and Paterson) annotated with sizes.
+*
+* Pushing and popping at both ends is O(1) amortized, indexing, split_at and concat are O(log n).
+*/
+#[derive(Debug, Clone)]
+pub struct FingerTree<T : Clone> {
+    root : Rc<Tree<T>>,
+}
+
+enum Pending<'a, T> {
+    Tree(&'a Tree<T>),
+    Item(&'a Item<T>),
+}
+
+pub struct Iter<'a, T : Clone> {
+    stack : Vec<Pending<'a, T>>,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+impl<T> Item<T> {
+    fn size(&self) -> usize {
+        match self {
+            Item::Leaf(_) => 1,
+            Item::Node(size, _) => *size,
+        }
+    }
+
+    fn node(items : Digit<T>) -> Rc<Item<T>> {
+        Rc::from(Item::Node(digit_size(&items), items))
+    }
+
+    fn children(&self) -> &[Rc<Item<T>>] {
+        match self {
+            Item::Node(_, items) => items,
+            Item::Leaf(_) => panic!("A leaf has no children."),
+        }
+    }
+
+    fn get(&self, index : usize) -> &T {
+        match self {
+            Item::Leaf(value) => value,
+            Item::Node(_, items) => {
+                let (position, offset) = find_in_digit(items, index);
+                items[position].get(index - offset)
+            }
+        }
+    }
+}
+
+fn digit_size<T>(digit : &[Rc<Item<T>>]) -> usize {
+    digit.iter().map(|item| item.size()).sum()
+}
+
+//Position of the item holding index and the number of elements before it.
+fn find_in_digit<T>(digit : &[Rc<Item<T>>], index : usize) -> (usize, usize) {
+    let mut offset = 0;
+    for (position, item) in digit.iter().enumerate() {
+        if index < offset + item.size() {
+            return (position, offset);
+        }
+        offset += item.size();
+    }
+    panic!("Invalid index")
+}
+
+//Groups at least 2 items into nodes of 2 or 3 items.
+fn nodes<T>(mut items : Digit<T>) -> Digit<T> {
+    let mut result = Vec::new();
+    while items.len() > 4 {
+        let rest = items.split_off(3);
+        result.push(Item::node(items));
+        items = rest;
+    }
+    if items.len() == 4 {
+        let rest = items.split_off(2);
+        result.push(Item::node(items));
+        result.push(Item::node(rest));
+    } else {
+        result.push(Item::node(items));
+    }
+    result
+}
+
+impl<T> Tree<T> {
+    fn size(&self) -> usize {
+        match self {
+            Tree::Empty => 0,
+            Tree::Single(item) => item.size(),
+            Tree::Deep(size, _, _, _) => *size,
+        }
+    }
+
+    fn empty() -> Rc<Tree<T>> {
+        Rc::from(Tree::Empty)
+    }
+
+    fn deep(prefix : Digit<T>, middle : Rc<Tree<T>>, suffix : Digit<T>) -> Rc<Tree<T>> {
+        let size = digit_size(&prefix) + middle.size() + digit_size(&suffix);
+        Rc::from(Tree::Deep(size, prefix, middle, suffix))
+    }
+
+    fn from_digit(digit : Digit<T>) -> Rc<Tree<T>> {
+        digit.into_iter().fold(Tree::empty(), |tree, item| Tree::push_back(&tree, item))
+    }
+
+    fn push_front(tree : &Rc<Tree<T>>, item : Rc<Item<T>>) -> Rc<Tree<T>> {
+        match tree.as_ref() {
+            Tree::Empty => Rc::from(Tree::Single(item)),
+            Tree::Single(other) => Tree::deep(vec![item], Tree::empty(), vec![other.clone()]),
+            Tree::Deep(_, prefix, middle, suffix) => {
+                if prefix.len() == 4 {
+                    let node = Item::node(prefix[1..].to_vec());
+                    Tree::deep(vec![item, prefix[0].clone()], Tree::push_front(middle, node), suffix.clone())
+                } else {
+                    let mut new_prefix = vec![item];
+                    new_prefix.extend(prefix.iter().cloned());
+                    Tree::deep(new_prefix, middle.clone(), suffix.clone())
+                }
+            }
+        }
+    }
+
+    fn push_back(tree : &Rc<Tree<T>>, item : Rc<Item<T>>) -> Rc<Tree<T>> {
+        match tree.as_ref() {
+            Tree::Empty => Rc::from(Tree::Single(item)),
+            Tree::Single(other) => Tree::deep(vec![other.clone()], Tree::empty(), vec![item]),
+            Tree::Deep(_, prefix, middle, suffix) => {
+                if suffix.len() == 4 {
+                    let node = Item::node(suffix[..3].to_vec());
+                    Tree::deep(prefix.clone(), Tree::push_back(middle, node), vec![suffix[3].clone(), item])
+                } else {
+                    let mut new_suffix = suffix.clone();
+                    new_suffix.push(item);
+                    Tree::deep(prefix.clone(), middle.clone(), new_suffix)
+                }
+            }
+        }
+    }
+
+    fn view_front(tree : &Rc<Tree<T>>) -> Option<View<T>> {
+        match tree.as_ref() {
+            Tree::Empty => None,
+            Tree::Single(item) => Some((item.clone(), Tree::empty())),
+            Tree::Deep(_, prefix, middle, suffix) => Some((prefix[0].clone(),
+                Tree::deep_front(prefix[1..].to_vec(), middle, suffix.clone()))),
+        }
+    }
+
+    fn view_back(tree : &Rc<Tree<T>>) -> Option<View<T>> {
+        match tree.as_ref() {
+            Tree::Empty => None,
+            Tree::Single(item) => Some((item.clone(), Tree::empty())),
+            Tree::Deep(_, prefix, middle, suffix) => Some((suffix[suffix.len() - 1].clone(),
+                Tree::deep_back(prefix.clone(), middle, suffix[..suffix.len() - 1].to_vec()))),
+        }
+    }
+
+    //Like deep, but prefix may be empty.
+    fn deep_front(prefix : Digit<T>, middle : &Rc<Tree<T>>, suffix : Digit<T>) -> Rc<Tree<T>> {
+        if !prefix.is_empty() {
+            return Tree::deep(prefix, middle.clone(), suffix);
+        }
+        match Tree::view_front(middle) {
+            None => Tree::from_digit(suffix),
+            Some((node, rest)) => Tree::deep(node.children().to_vec(), rest, suffix),
+        }
+    }
+
+    //Like deep, but suffix may be empty.
+    fn deep_back(prefix : Digit<T>, middle : &Rc<Tree<T>>, suffix : Digit<T>) -> Rc<Tree<T>> {
+        if !suffix.is_empty() {
+            return Tree::deep(prefix, middle.clone(), suffix);
+        }
+        match Tree::view_back(middle) {
+            None => Tree::from_digit(prefix),
+            Some((node, rest)) => Tree::deep(prefix, rest, node.children().to_vec()),
+        }
+    }
+
+    //Concatenates left, the items in between and right.
+    fn app3(left : &Rc<Tree<T>>, between : Digit<T>, right : &Rc<Tree<T>>) -> Rc<Tree<T>> {
+        match (left.as_ref(), right.as_ref()) {
+            (Tree::Empty, _) => between.into_iter().rev().fold(right.clone(), |tree, item| Tree::push_front(&tree, item)),
+            (_, Tree::Empty) => between.into_iter().fold(left.clone(), |tree, item| Tree::push_back(&tree, item)),
+            (Tree::Single(item), _) => Tree::push_front(&Tree::app3(&Tree::empty(), between, right), item.clone()),
+            (_, Tree::Single(item)) => Tree::push_back(&Tree::app3(left, between, &Tree::empty()), item.clone()),
+            (Tree::Deep(_, prefix1, middle1, suffix1), Tree::Deep(_, prefix2, middle2, suffix2)) => {
+                let mut items = suffix1.clone();
+                items.extend(between);
+                items.extend(prefix2.iter().cloned());
+                Tree::deep(prefix1.clone(), Tree::app3(middle1, nodes(items), middle2), suffix2.clone())
+            }
+        }
+    }
+
+    //tree must not be empty. Returns the item holding index and the trees at both sides of it.
+    fn split(tree : &Rc<Tree<T>>, index : usize) -> Split<T> {
+        match tree.as_ref() {
+            Tree::Empty => panic!("Cannot split an empty tree."),
+            Tree::Single(item) => (Tree::empty(), item.clone(), Tree::empty()),
+            Tree::Deep(_, prefix, middle, suffix) => {
+                let prefix_size = digit_size(prefix);
+                if index < prefix_size {
+                    let (before, item, after) = split_digit(prefix, index);
+                    return (Tree::from_digit(before), item, Tree::deep_front(after, middle, suffix.clone()));
+                }
+
+                let middle_size = middle.size();
+                if index < prefix_size + middle_size {
+                    let (left, node, right) = Tree::split(middle, index - prefix_size);
+                    let (before, item, after) = split_digit(node.children(), index - prefix_size - left.size());
+                    return (Tree::deep_back(prefix.clone(), &left, before), item,
+                        Tree::deep_front(after, &right, suffix.clone()));
+                }
+
+                let (before, item, after) = split_digit(suffix, index - prefix_size - middle_size);
+                (Tree::deep_back(prefix.clone(), middle, before), item, Tree::from_digit(after))
+            }
+        }
+    }
+
+    fn get(&self, index : usize) -> &T {
+        match self {
+            Tree::Empty => panic!("Invalid index"),
+            Tree::Single(item) => item.get(index),
+            Tree::Deep(_, prefix, middle, suffix) => {
+                let prefix_size = digit_size(prefix);
+                if index < prefix_size {
+                    let (position, offset) = find_in_digit(prefix, index);
+                    return prefix[position].get(index - offset);
+                }
+                if index < prefix_size + middle.size() {
+                    return middle.get(index - prefix_size);
+                }
+                let index = index - prefix_size - middle.size();
+                let (position, offset) = find_in_digit(suffix, index);
+                suffix[position].get(index - offset)
+            }
+        }
+    }
+}
+
+fn split_digit<T>(digit : &[Rc<Item<T>>], index : usize) -> DigitSplit<T> {
+    let (position, _) = find_in_digit(digit, index);
+    (digit[..position].to_vec(), digit[position].clone(), digit[position + 1..].to_vec())
+}
+
+impl<T : Clone> FingerTree<T> {
+    pub fn new() -> FingerTree<T> {
+        FingerTree { root : Tree::empty() }
+    }
+
+    pub fn from_list(list : &LinkedNode<T>) -> FingerTree<T> {
+        list.iter().fold(FingerTree::new(), |tree, value| tree.push_back(value.clone()))
+    }
+
+    pub fn to_list(&self) -> Rc<LinkedNode<T>> {
+        LinkedNode::prepend_all(self.iter().cloned().collect(), &LinkedNode::nil())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.size() == 0
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { stack : vec![Pending::Tree(&self.root)] }
+    }
+
+    /**
+    * @brief Inserts an element at the front.
+    *
+    * This method is implemented in O(1) amortized for time and memory.
+    */
+    pub fn push_front(&self, value : T) -> FingerTree<T> {
+        FingerTree { root : Tree::push_front(&self.root, Rc::from(Item::Leaf(value))) }
+    }
+
+    /**
+    * @brief Inserts an element at the back.
+    *
+    * This method is implemented in O(1) amortized for time and memory.
+    */
+    pub fn push_back(&self, value : T) -> FingerTree<T> {
+        FingerTree { root : Tree::push_back(&self.root, Rc::from(Item::Leaf(value))) }
+    }
+
+    /**
+    * @brief Splits the sequence into its first element and the rest.
+    *
+    * @return Option<(&T, Self)> None if the sequence is empty.
+    */
+    pub fn pop_front(&self) -> Option<(&T, FingerTree<T>)> {
+        let (_, rest) = Tree::view_front(&self.root)?;
+        Some((self.front().unwrap(), FingerTree { root : rest }))
+    }
+
+    /**
+    * @brief Splits the sequence into the rest and its last element.
+    *
+    * @return Option<(Self, &T)> None if the sequence is empty.
+    */
+    pub fn pop_back(&self) -> Option<(FingerTree<T>, &T)> {
+        let (_, rest) = Tree::view_back(&self.root)?;
+        Some((FingerTree { root : rest }, self.back().unwrap()))
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.root.size().checked_sub(1)?)
+    }
+
+    /**
+    * @brief returns the value stored at the specified index.
+    *
+    * This method is implemented in O(log n) for time and O(1) for memory.
+    *
+    * @return Option<&T> None if index is out of bounds.
+    */
+    pub fn get(&self, index : usize) -> Option<&T> {
+        if index >= self.root.size() {
+            return None;
+        }
+        Some(self.root.get(index))
+    }
+
+    /**
+    * @brief Splits the sequence in two, the element at index becomes the first of the second part.
+    *
+    * This method is implemented in O(log n) for time and memory.
+    *
+    * @return (Self, Self) The first index elements and the remaining ones.
+    */
+    pub fn split_at(&self, index : usize) -> (FingerTree<T>, FingerTree<T>) {
+        if index >= self.root.size() {
+            return (self.clone(), FingerTree::new());
+        }
+
+        let (left, item, right) = Tree::split(&self.root, index);
+        (FingerTree { root : left }, FingerTree { root : Tree::push_front(&right, item) })
+    }
+
+    /**
+    * @brief Joins both sequences.
+    *
+    * This method is implemented in O(log(min(n, m))) for time and memory.
+    *
+    * @return Self self followed by other.
+    */
+    pub fn concat(&self, other : &FingerTree<T>) -> FingerTree<T> {
+        FingerTree { root : Tree::app3(&self.root, Vec::new(), &other.root) }
+    }
+}
+
+impl<'a, T : Clone> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            match self.stack.pop()? {
+                Pending::Item(Item::Leaf(value)) => return Some(value),
+                Pending::Item(Item::Node(_, items)) => {
+                    self.stack.extend(items.iter().rev().map(|item| Pending::Item(item)));
+                }
+                Pending::Tree(Tree::Empty) => {}
+                Pending::Tree(Tree::Single(item)) => self.stack.push(Pending::Item(item)),
+                Pending::Tree(Tree::Deep(_, prefix, middle, suffix)) => {
+                    self.stack.extend(suffix.iter().rev().map(|item| Pending::Item(item)));
+                    self.stack.push(Pending::Tree(middle));
+                    self.stack.extend(prefix.iter().rev().map(|item| Pending::Item(item)));
+                }
+            }
+        }
+    }
+}
+
+impl<T : Clone> Default for FingerTree<T> {
+    fn default() -> Self {
+        FingerTree::new()
+    }
+}
+
+impl<T> fmt::Display for FingerTree<T>
+    where T : fmt::Display + Clone
+{
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        let values : Vec<String> = self.iter().map(|value| value.to_string()).collect();
+        write!(f, "[{}]", values.join(","))
+    }
+}
+
+impl<T> InmutList<T> for FingerTree<T>
+    where T : Clone
+{
+    fn append(list : Self, other_list : Self) -> Self {
+        list.concat(&other_list)
+    }
+
+    //O(log n) for time and memory.
+    fn insert_at(list : Self, insert_list : Self, index : usize) -> Self {
+        if index > list.size() {
+            panic!("Invalid index at function insert_at.");
+        }
+
+        let (left, right) = list.split_at(index);
+        left.concat(&insert_list).concat(&right)
+    }
+
+    //O(log n) for time and memory.
+    fn remove_at(list : Self, index : usize, count : usize) -> Self {
+        if count == 0 {
+            return list;
+        }
+        if index + count > list.size() {
+            panic!("Invalid count or index at remove_at function.");
+        }
+
+        let (left, right) = list.split_at(index);
+        let (_, right) = right.split_at(count);
+        left.concat(&right)
+    }
+
+    fn value_at(list : Self, index : usize) -> T {
+        match list.get(index) {
+            Some(value) => value.clone(),
+            None => panic!("Invalid index at value_at function."),
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.root.size()
+    }
+
+    fn reverse(&self) -> Self {
+        self.iter().fold(FingerTree::new(), |tree, value| tree.push_front(value.clone()))
+    }
+}
@@ -0,0 +1,292 @@
+use core::cell::Cell;
+use core::fmt;
+use core::marker::{PhantomData, PhantomPinned};
+use core::pin::Pin;
+use core::ptr::NonNull;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use alloc::{string::{String, ToString}, vec::Vec};
+
+//Every IntrusiveList gets its own id so a Link knows which list it is on.
+static NEXT_ID : AtomicUsize = AtomicUsize::new(1);
+
+/**
+* Field to embed in a struct so its values can be put on an IntrusiveList.
+*
+* A value can be on as many lists at the same time as it has Link fields, one list per field.
+* The neighbours are kept as pointers to the whole values, not to their links.
+*/
+pub struct Link {
+    prev : Cell<Option<NonNull<u8>>>,
+    next : Cell<Option<NonNull<u8>>>,
+    //Id of the list the value is on, 0 if it's on none.
+    owner : Cell<usize>,
+    _pin : PhantomPinned,
+}
+
+/**
+* Finds the Link a list uses inside its values, usually implemented with intrusive_adapter!.
+*
+* # Safety
+*
+* link must always return the same field of value, and that field must not be returned by an
+* adapter for another type.
+*/
+pub unsafe trait Adapter {
+    type Value;
+
+    fn link(value : &Self::Value) -> &Link;
+}
+
+/**
+* Implements Adapter for a Link field.
+*
+* intrusive_adapter!(pub ReadyAdapter = Task { ready }); declares ReadyAdapter, which lets an
+* IntrusiveList<ReadyAdapter> thread Task values through their ready field.
+*/
+#[macro_export]
+macro_rules! intrusive_adapter {
+    ($vis:vis $adapter:ident = $value:ty { $field:ident }) => {
+        $vis struct $adapter;
+
+        unsafe impl $crate::intrusive::Adapter for $adapter {
+            type Value = $value;
+
+            fn link(value : &$value) -> &$crate::intrusive::Link {
+                &value.$field
+            }
+        }
+    };
+}
+
+/**
+* Doubly linked list of values owned by the caller, it never allocates.
+*
+* The values are borrowed for 'a and pinned, so they can't move or be dropped while they are
+* linked. push_back, push_front, remove and the pops are O(1). When the list is dropped every
+* value is unlinked and can be put on another list.
+*/
+pub struct IntrusiveList<'a, A : Adapter> {
+    head : Option<NonNull<A::Value>>,
+    tail : Option<NonNull<A::Value>>,
+    n : usize,
+    id : usize,
+    _values : PhantomData<Pin<&'a A::Value>>,
+}
+
+pub struct IntrusiveIter<'a, A : Adapter> {
+    current : Option<NonNull<A::Value>>,
+    _values : PhantomData<&'a A::Value>,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+impl Link {
+    pub const fn new() -> Link {
+        Link {
+            prev : Cell::new(None), next : Cell::new(None), owner : Cell::new(0), _pin : PhantomPinned,
+        }
+    }
+
+    pub fn is_linked(&self) -> bool {
+        self.owner.get() != 0
+    }
+
+    fn reset(&self) {
+        self.prev.set(None);
+        self.next.set(None);
+        self.owner.set(0);
+    }
+}
+
+impl Default for Link {
+    fn default() -> Self {
+        Link::new()
+    }
+}
+
+impl fmt::Debug for Link {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Link {{ linked : {} }}", self.is_linked())
+    }
+}
+
+impl<'a, A : Adapter> IntrusiveList<'a, A> {
+    pub fn new() -> IntrusiveList<'a, A> {
+        IntrusiveList {
+            head : None, tail : None, n : 0,
+            id : NEXT_ID.fetch_add(1, Ordering::Relaxed), _values : PhantomData,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    pub fn empty(&self) -> bool {
+        self.n == 0
+    }
+
+    //The iterator borrows the list, so it can't be changed while the walk follows its links.
+    pub fn iter(&self) -> IntrusiveIter<'_, A> {
+        IntrusiveIter { current : self.head, _values : PhantomData }
+    }
+
+    //True if value is on this list, O(1).
+    pub fn contains(&self, value : &A::Value) -> bool {
+        A::link(value).owner.get() == self.id
+    }
+
+    pub fn front(&self) -> Option<Pin<&'a A::Value>> {
+        self.head.map(|value| unsafe { Pin::new_unchecked(value.as_ref()) })
+    }
+
+    pub fn back(&self) -> Option<Pin<&'a A::Value>> {
+        self.tail.map(|value| unsafe { Pin::new_unchecked(value.as_ref()) })
+    }
+
+    //Every pointer held by the list is to a value borrowed for 'a.
+    fn link_of(value : NonNull<A::Value>) -> &'a Link {
+        A::link(unsafe { value.as_ref() })
+    }
+
+    /**
+    * @brief Links value at the end of the list.
+    *
+    * @return u8 0 on success, 1 if value is already on a list through this adapter's link.
+    */
+    pub fn push_back(&mut self, value : Pin<&'a A::Value>) -> u8 {
+        let value = value.get_ref();
+        let link = A::link(value);
+        if link.is_linked() {
+            return 1;
+        }
+
+        let pointer = NonNull::from(value);
+        link.owner.set(self.id);
+        link.prev.set(self.tail.map(NonNull::cast));
+        match self.tail {
+            Some(tail) => Self::link_of(tail).next.set(Some(pointer.cast())),
+            None => self.head = Some(pointer),
+        }
+        self.tail = Some(pointer);
+        self.n += 1;
+        0
+    }
+
+    /**
+    * @brief Links value at the beginning of the list.
+    *
+    * @return u8 0 on success, 1 if value is already on a list through this adapter's link.
+    */
+    pub fn push_front(&mut self, value : Pin<&'a A::Value>) -> u8 {
+        let value = value.get_ref();
+        let link = A::link(value);
+        if link.is_linked() {
+            return 1;
+        }
+
+        let pointer = NonNull::from(value);
+        link.owner.set(self.id);
+        link.next.set(self.head.map(NonNull::cast));
+        match self.head {
+            Some(head) => Self::link_of(head).prev.set(Some(pointer.cast())),
+            None => self.tail = Some(pointer),
+        }
+        self.head = Some(pointer);
+        self.n += 1;
+        0
+    }
+
+    /**
+    * @brief Unlinks value, the value itself is left where it is.
+    *
+    * @return bool true if value was on this list.
+    */
+    pub fn remove(&mut self, value : &A::Value) -> bool {
+        let link = A::link(value);
+        if link.owner.get() != self.id {
+            return false;
+        }
+
+        let prev : Option<NonNull<A::Value>> = link.prev.get().map(NonNull::cast);
+        let next : Option<NonNull<A::Value>> = link.next.get().map(NonNull::cast);
+        match prev {
+            Some(prev) => Self::link_of(prev).next.set(next.map(NonNull::cast)),
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => Self::link_of(next).prev.set(prev.map(NonNull::cast)),
+            None => self.tail = prev,
+        }
+        link.reset();
+        self.n -= 1;
+        true
+    }
+
+    pub fn pop_front(&mut self) -> Option<Pin<&'a A::Value>> {
+        let value = self.front()?;
+        self.remove(value.get_ref());
+        Some(value)
+    }
+
+    pub fn pop_back(&mut self) -> Option<Pin<&'a A::Value>> {
+        let value = self.back()?;
+        self.remove(value.get_ref());
+        Some(value)
+    }
+
+    //Unlinks every value.
+    pub fn clear(&mut self) {
+        let mut current = self.head;
+        while let Some(value) = current {
+            let link = Self::link_of(value);
+            current = link.next.get().map(NonNull::cast);
+            link.reset();
+        }
+        self.head = None;
+        self.tail = None;
+        self.n = 0;
+    }
+}
+
+impl<'a, A : Adapter> Default for IntrusiveList<'a, A> {
+    fn default() -> Self {
+        IntrusiveList::new()
+    }
+}
+
+impl<'a, A : Adapter> Drop for IntrusiveList<'a, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<'a, A : Adapter> Iterator for IntrusiveIter<'a, A> {
+    type Item = &'a A::Value;
+
+    fn next(&mut self) -> Option<&'a A::Value> {
+        let value : &'a A::Value = unsafe { self.current?.as_ref() };
+        self.current = A::link(value).next.get().map(NonNull::cast);
+        Some(value)
+    }
+}
+
+impl<'l, 'a, A : Adapter> IntoIterator for &'l IntrusiveList<'a, A> {
+    type Item = &'l A::Value;
+    type IntoIter = IntrusiveIter<'l, A>;
+
+    fn into_iter(self) -> IntrusiveIter<'l, A> {
+        self.iter()
+    }
+}
+
+impl<'a, A> fmt::Display for IntrusiveList<'a, A>
+    where A : Adapter, A::Value : fmt::Display
+{
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        let values : Vec<String> = self.iter().map(|value| value.to_string()).collect();
+        write!(f, "[{}]", values.join(","))
+    }
+}
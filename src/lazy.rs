@@ -0,0 +1,220 @@
+use core::cell::{Cell, OnceCell};
+use alloc::rc::Rc;
+use alloc::boxed::Box;
+
+use crate::list::*;
+
+//An evaluated cell, None is the end of the list.
+type Node<T> = Option<(T, LazyList<T>)>;
+type Thunk<T> = Box<dyn FnOnce() -> Node<T>>;
+type Step<T, S> = Rc<dyn Fn(&S) -> Option<(T, S)>>;
+
+struct LazyCell<T : Clone> {
+    value : OnceCell<Node<T>>,
+    thunk : Cell<Option<Thunk<T>>>,
+}
+
+/**
+* Persistent list whose tail is only computed the first time it is needed.
+*
+* Clones share their cells, so once a cell has been forced every clone sees the result,
+* just like LinkedNode shares its suffixes. This makes infinite lists possible.
+*/
+#[derive(Clone)]
+pub struct LazyList<T : Clone> {
+    cell : Rc<LazyCell<T>>,
+}
+
+pub struct Iter<T : Clone> {
+    current : LazyList<T>,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+impl<T : Clone + 'static> LazyList<T> {
+    fn evaluated(node : Node<T>) -> LazyList<T> {
+        LazyList {
+            cell : Rc::from(LazyCell { value : OnceCell::from(node), thunk : Cell::new(None) }),
+        }
+    }
+
+    //Creates a list whose contents are computed by thunk when first forced.
+    pub fn lazy<F : FnOnce() -> Node<T> + 'static>(thunk : F) -> LazyList<T> {
+        LazyList {
+            cell : Rc::from(LazyCell { value : OnceCell::new(), thunk : Cell::new(Some(Box::new(thunk))) }),
+        }
+    }
+
+    pub fn nil() -> LazyList<T> {
+        LazyList::evaluated(None)
+    }
+
+    pub fn cons(value : T, tail : LazyList<T>) -> LazyList<T> {
+        LazyList::evaluated(Some((value, tail)))
+    }
+
+    pub fn from_list(list : Rc<LinkedNode<T>>) -> LazyList<T> {
+        LazyList::lazy(move || {
+            let (value, tail) = list.uncons()?;
+            Some((value.clone(), LazyList::from_list(tail)))
+        })
+    }
+
+    /**
+    * @brief The infinite list seed, f(seed), f(f(seed))...
+    */
+    pub fn iterate<F : Fn(&T) -> T + 'static>(seed : T, f : F) -> LazyList<T> {
+        LazyList::iterate_rc(seed, Rc::from(f))
+    }
+
+    fn iterate_rc(seed : T, f : Rc<dyn Fn(&T) -> T>) -> LazyList<T> {
+        LazyList::cons(seed.clone(), LazyList::lazy(move || {
+            let next = f(&seed);
+            LazyList::iterate_rc(next, f).force().clone()
+        }))
+    }
+
+    /**
+    * @brief The infinite list value, value, value...
+    */
+    pub fn repeat(value : T) -> LazyList<T> {
+        LazyList::iterate(value, |value| value.clone())
+    }
+
+    /**
+    * @brief Repeats the elements of list forever.
+    *
+    * @return LazyList<T> An infinite list, or an empty one if list is empty.
+    */
+    pub fn cycle(list : Rc<LinkedNode<T>>) -> LazyList<T> {
+        LazyList::cycle_from(list.clone(), list)
+    }
+
+    fn cycle_from(current : Rc<LinkedNode<T>>, list : Rc<LinkedNode<T>>) -> LazyList<T> {
+        LazyList::lazy(move || {
+            let (value, tail) = match current.uncons() {
+                Some(cell) => cell,
+                None => list.uncons()?,
+            };
+            Some((value.clone(), LazyList::cycle_from(tail, list.clone())))
+        })
+    }
+
+    /**
+    * @brief Builds a list from a seed, f returns the next element and the next seed or None to stop.
+    */
+    pub fn unfold<S : 'static, F : Fn(&S) -> Option<(T, S)> + 'static>(seed : S, f : F) -> LazyList<T> {
+        LazyList::unfold_rc(seed, Rc::from(f))
+    }
+
+    fn unfold_rc<S : 'static>(seed : S, f : Step<T, S>) -> LazyList<T> {
+        LazyList::lazy(move || {
+            let (value, next) = f(&seed)?;
+            Some((value, LazyList::unfold_rc(next, f)))
+        })
+    }
+
+    //Evaluates the first cell, every clone shares the result.
+    fn force(&self) -> &Node<T> {
+        self.cell.value.get_or_init(|| {
+            let thunk = self.cell.thunk.take().expect("LazyList forced while it was being evaluated.");
+            thunk()
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.force().is_none()
+    }
+
+    pub fn uncons(&self) -> Option<(&T, LazyList<T>)> {
+        self.force().as_ref().map(|(value, tail)| (value, tail.clone()))
+    }
+
+    pub fn head(&self) -> Option<&T> {
+        self.force().as_ref().map(|(value, _)| value)
+    }
+
+    pub fn tail(&self) -> Option<LazyList<T>> {
+        self.force().as_ref().map(|(_, tail)| tail.clone())
+    }
+
+    pub fn iter(&self) -> Iter<T> {
+        Iter { current : self.clone() }
+    }
+
+    pub fn map<U : Clone + 'static, F : Fn(&T) -> U + 'static>(&self, f : F) -> LazyList<U> {
+        LazyList::map_rc(self.clone(), Rc::from(f))
+    }
+
+    fn map_rc<U : Clone + 'static>(list : LazyList<T>, f : Rc<dyn Fn(&T) -> U>) -> LazyList<U> {
+        LazyList::lazy(move || {
+            let (value, tail) = list.uncons()?;
+            Some((f(value), LazyList::map_rc(tail, f)))
+        })
+    }
+
+    //Forcing a cell of the result may walk an unbounded number of cells if none satisfies predicate.
+    pub fn filter<P : Fn(&T) -> bool + 'static>(&self, predicate : P) -> LazyList<T> {
+        LazyList::filter_rc(self.clone(), Rc::from(predicate))
+    }
+
+    fn filter_rc(list : LazyList<T>, predicate : Rc<dyn Fn(&T) -> bool>) -> LazyList<T> {
+        LazyList::lazy(move || {
+            let mut current = list;
+            loop {
+                let (value, tail) = current.uncons()?;
+                if predicate(value) {
+                    return Some((value.clone(), LazyList::filter_rc(tail, predicate)));
+                }
+                current = tail;
+            }
+        })
+    }
+
+    pub fn zip<U : Clone + 'static>(&self, other : &LazyList<U>) -> LazyList<(T, U)> {
+        let (left, right) = (self.clone(), other.clone());
+        LazyList::lazy(move || {
+            let (a, left_tail) = left.uncons()?;
+            let (b, right_tail) = right.uncons()?;
+            Some(((a.clone(), b.clone()), left_tail.zip(&right_tail)))
+        })
+    }
+
+    /**
+    * @brief Forces the first n elements.
+    *
+    * @return Rc<LinkedNode<T>> A list with at most n elements.
+    */
+    pub fn take(&self, n : usize) -> Rc<LinkedNode<T>> {
+        LinkedNode::prepend_all(self.iter().take(n).collect(), &LinkedNode::nil())
+    }
+}
+
+impl<T : Clone + 'static> Iterator for Iter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let (value, tail) = self.current.uncons().map(|(value, tail)| (value.clone(), tail))?;
+        self.current = tail;
+        Some(value)
+    }
+}
+
+//Long evaluated lists would overflow the stack if the cells were dropped recursively.
+impl<T : Clone> Drop for LazyList<T> {
+    fn drop(&mut self) {
+        let mut node = match Rc::get_mut(&mut self.cell) {
+            Some(cell) => cell.value.take(),
+            None => return,
+        };
+
+        while let Some(Some((_, mut tail))) = node {
+            node = match Rc::get_mut(&mut tail.cell) {
+                Some(cell) => cell.value.take(),
+                None => None,
+            };
+        }
+    }
+}
@@ -1,11 +1,74 @@
+//Without the default std feature the crate only needs core and alloc.
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
 
 pub mod list; 
+pub mod random_access;
+pub mod deque;
+pub mod catenable;
+pub mod finger_tree;
+pub mod lazy;
+pub mod zipper;
+pub mod set_ops;
+pub mod sorted_list;
+pub mod skip_list;
+pub mod unrolled;
+pub mod rope;
+pub mod xor_list;
+pub mod intrusive;
+pub mod static_list;
+pub mod bounded;
+#[cfg(feature = "std")]
+pub mod blocking;
+pub mod work_stealing;
+#[cfg(feature = "std")]
+pub mod concurrent;
+mod random;
 
 #[cfg(test)]
 mod tests {
     use crate::list::*;
-    use std::rc::Rc;
+    use crate::random_access::*;
+    use crate::deque::*;
+    use crate::catenable::*;
+    use crate::finger_tree::*;
+    use crate::lazy::*;
+    use crate::zipper::*;
+    use crate::set_ops;
+    use crate::sorted_list::*;
+    use crate::skip_list::*;
+    use crate::unrolled::*;
+    use crate::rope::*;
+    use crate::xor_list::*;
+    use crate::intrusive::*;
+    use crate::static_list::*;
+    use crate::bounded::*;
+    #[cfg(feature = "std")]
+    use crate::blocking::*;
+    #[cfg(feature = "std")]
+    use crate::work_stealing::*;
+    #[cfg(feature = "std")]
+    use crate::concurrent::*;
+    use core::pin::pin;
+    use core::cmp;
+    use alloc::rc::Rc;
+    use alloc::{boxed::Box, collections::VecDeque, format, string::{String, ToString}, vec, vec::Vec};
+
+    //Pseudo-random numbers for the model tests, the same seed always gives the same operations.
+    fn lcg(seed : &mut usize) -> usize {
+        *seed = (*seed * 1103515245 + 12345) % 2147483648;
+        *seed
+    }
+
+    //Persistent versions kept along a model test must still hold the values they had.
+    fn assert_versions<'a, L, I>(versions : &'a [(L, Vec<u64>)], iter : impl Fn(&'a L) -> I)
+        where I : Iterator<Item = &'a u64>
+    {
+        for (version, expected) in versions {
+            assert!(iter(version).eq(expected.iter()));
+        }
+    }
 
     #[test]
     fn append_insert_and_remove() {
@@ -153,22 +216,22 @@ mod tests {
         let mut arr : [u64; 50] = [0; 50];
         
         //Testing empty()
-        assert_eq!(deq.empty(), true); 
+        assert!(deq.empty()); 
 
         deq.push_back(1);
-        assert_eq!(deq.empty(), false);
+        assert!(!deq.empty());
 
         //Testing push_back()
         deq.pop_back();
 
-        for i in 0..(size) {
+        for (i, slot) in arr.iter_mut().enumerate() {
             deq.push_back(i as u64);
-            arr[i as usize] = i as u64;
+            *slot = i as u64;
         }
         
         assert_eq!(deq.size(), size);
-        for i in 0..(size) {
-            assert_eq!(arr[i] , *deq.value_at(i));
+        for (i, value) in arr.iter().enumerate() {
+            assert_eq!(*value , *deq.value_at(i));
         }
 
         //Testing pop_back()
@@ -176,7 +239,7 @@ mod tests {
             assert_eq!(deq.pop_back(), i as u64);
         }
 
-        assert_eq!(deq.size(), 0 as usize);
+        assert_eq!(deq.size(), 0_usize);
 
         //Testing push_front()
         for i in 0..(size) {
@@ -185,8 +248,8 @@ mod tests {
         }
 
         assert_eq!(deq.size(), size);
-        for i in 0..(size) {
-            assert_eq!(arr[i] , *deq.value_at(i));
+        for (i, value) in arr.iter().enumerate() {
+            assert_eq!(*value , *deq.value_at(i));
         }
 
         //Testing pop_front()
@@ -194,7 +257,7 @@ mod tests {
             assert_eq!(deq.pop_front(), i as u64);
         }
 
-        assert_eq!(deq.empty(), true);
+        assert!(deq.empty());
 
     }
 
@@ -236,8 +299,6 @@ mod tests {
     #[test]
     fn dll_clone_test() {
         let mut dllist : DoublyLinkedList<u64> = DoublyLinkedList::new();
-        let str :  String;
-        let clone_str : String;
 
         for i in 0..7 {
             dllist.append(i);
@@ -245,12 +306,1616 @@ mod tests {
 
         let clone = dllist.clone();
         
-        str = format!("{}", dllist);
-        clone_str = format!("{}", clone);
+        let str = format!("{}", dllist);
+        let clone_str = format!("{}", clone);
 
         assert_eq!(str, clone_str);
         
 
     }
 
+    #[test]
+    fn linked_node_combinators() {
+        let mut llist : Rc<LinkedNode<u64>> = LinkedNode::nil();
+        for i in 1..7 {
+            llist = InmutList::append(llist, LinkedNode::new(i));
+        }
+
+        assert_eq!(format!("{}", llist.map(|x| x * 10)), "[10,20,30,40,50,60]");
+        assert_eq!(format!("{}", llist.filter(|x| x % 2 == 0)), "[2,4,6]");
+        assert_eq!(format!("{}", llist.filter_map(|x| if *x > 4 {Some(x + 1)} else {None})), "[6,7]");
+        assert_eq!(format!("{}", llist.take(2).flat_map(|x| LinkedNode::cons(LinkedNode::new(*x), LinkedNode::new(*x)))), 
+            "[1,1,2,2]");
+        assert_eq!(format!("{}", llist.take(3)), "[1,2,3]");
+        assert_eq!(format!("{}", llist.skip(4)), "[5,6]");
+        assert_eq!(format!("{}", llist.skip(10)), "[]");
+        assert_eq!(format!("{}", llist.skip(0)), "[1,2,3,4,5,6]");
+        assert_eq!(format!("{}", llist.take_while(|x| *x < 4)), "[1,2,3]");
+        assert_eq!(format!("{}", llist.take(3).intersperse(0)), "[1,0,2,0,3]");
+
+        let pairs = llist.zip(&llist.skip(4));
+        assert_eq!(pairs.size(), 2);
+        assert_eq!(*pairs.value(), (1, 5));
+
+        assert_eq!(llist.fold_left(0, |acc, x| acc * 10 + x), 123456);
+        assert_eq!(llist.fold_right(0, |x, acc| acc * 10 + x), 654321);
+
+        let lists = LinkedNode::cons(LinkedNode::new(llist.take(2)), LinkedNode::new(llist.skip(4)));
+        assert_eq!(format!("{}", LinkedNode::concat(&lists)), "[1,2,5,6]");
+
+        let empty : Rc<LinkedNode<u64>> = LinkedNode::nil();
+        assert_eq!(format!("{}", empty.map(|x| x.to_string())), "[]");
+        assert_eq!(format!("{}", empty.intersperse(0)), "[]");
+    }
+
+    #[test]
+    fn linked_node_long_list() {
+        let mut llist : Rc<LinkedNode<u64>> = LinkedNode::nil();
+        for i in 0..200000 {
+            llist = LinkedNode::cons(LinkedNode::new(i), llist);
+        }
+
+        let evens = llist.filter(|x| x % 2 == 0).map(|x| x / 2);
+        assert_eq!(evens.fold_left(0, |acc, _| acc + 1), 100000);
+        assert_eq!(llist.fold_right(0, |x, acc| cmp::max(*x, acc)), 199999);
+    }
+
+    #[test]
+    fn linked_node_uncons() {
+        fn sum(list : Rc<LinkedNode<u64>>) -> u64 {
+            match list.view() {
+                ListView::Nil => 0,
+                ListView::Cons(head, tail) => head + sum(tail),
+            }
+        }
+
+        let mut llist : Rc<LinkedNode<u64>> = LinkedNode::nil();
+        assert!(llist.uncons().is_none());
+        assert_eq!(llist.head(), None);
+        assert!(llist.tail().is_none());
+
+        for i in 1..5 {
+            llist = InmutList::append(llist, LinkedNode::new(i));
+        }
+
+        let (head, tail) = llist.uncons().unwrap();
+        assert_eq!(*head, 1);
+        assert_eq!(format!("{}", tail), "[2,3,4]");
+        assert_eq!(llist.head(), Some(&1));
+        assert_eq!(format!("{}", llist.tail().unwrap().tail().unwrap()), "[3,4]");
+        assert_eq!(sum(llist.clone()), 10);
+
+        //Tails are the nodes of the list itself, they behave like any other list.
+        assert!(Rc::ptr_eq(&tail, &llist.tail().unwrap()));
+        assert!(Rc::ptr_eq(&llist.skip(2), &tail.tail().unwrap()));
+        assert_eq!(tail.size(), 3);
+        assert_eq!(format!("{}", InmutList::append(tail.clone(), LinkedNode::new(5))), "[2,3,4,5]");
+        assert_eq!(format!("{}", InmutList::insert_at(tail.clone(), LinkedNode::new(0), 0)), "[0,2,3,4]");
+        assert_eq!(format!("{}", tail.reverse()), "[4,3,2]");
+        assert_eq!(format!("{}", llist), "[1,2,3,4]");
+        assert!(llist.skip(10).is_empty());
+    }
+
+    #[test]
+    fn random_access_list() {
+        let mut ral : RandomAccessList<u64> = RandomAccessList::nil();
+        for i in (0..100).rev() {
+            ral = ral.cons(i);
+        }
+
+        assert_eq!(ral.size(), 100);
+        for i in 0..100 {
+            assert_eq!(*ral.get(i).unwrap(), i as u64);
+        }
+        assert!(ral.get(100).is_none());
+
+        let updated = ral.update(57, 1000);
+        assert_eq!(InmutList::value_at(updated.clone(), 57), 1000);
+        assert_eq!(InmutList::value_at(ral.clone(), 57), 57);
+
+        for i in 0..100 {
+            assert_eq!(ral.skip(i).head(), Some(&(i as u64)));
+        }
+
+        let (head, tail) = ral.uncons().unwrap();
+        assert_eq!(*head, 0);
+        assert_eq!(tail.size(), 99);
+
+    }
+
+    #[test]
+    fn random_access_list_model() {
+        let empty : RandomAccessList<u64> = RandomAccessList::nil();
+        assert!(empty.is_empty());
+        assert_eq!(empty.size(), 0);
+        assert!(empty.uncons().is_none());
+        assert!(empty.get(0).is_none());
+        assert!(empty.reverse().is_empty());
+        assert!(empty.to_list().is_empty());
+        assert!(InmutList::insert_at(empty.clone(), empty.clone(), 0).is_empty());
+
+        let single = RandomAccessList::new(7);
+        assert_eq!(single.get(0), Some(&7));
+        assert!(single.get(1).is_none());
+        assert!(single.tail().unwrap().is_empty());
+        assert!(InmutList::remove_at(single.clone(), 0, 1).is_empty());
+        assert_eq!(format!("{}", single.update(0, 8)), "[8]");
+
+        //Every operation is checked against a Vec, old versions must keep their contents.
+        let mut model : Vec<u64> = Vec::new();
+        let mut list : RandomAccessList<u64> = RandomAccessList::nil();
+        let mut versions : Vec<(RandomAccessList<u64>, Vec<u64>)> = Vec::new();
+        let mut seed : usize = 7;
+        for i in 0..400 {
+            let seed = lcg(&mut seed);
+            let size = model.len();
+            match i % 6 {
+                0 | 1 => {
+                    //Boundary indices 0 and size come up regularly.
+                    let index = if i % 12 == 0 { 0 } else if i % 12 == 1 { size } else { seed % (size + 1) };
+                    list = InmutList::insert_at(list, RandomAccessList::new(i), index);
+                    model.insert(index, i);
+                }
+                2 => {
+                    list = list.cons(i);
+                    model.insert(0, i);
+                }
+                3 => {
+                    list = InmutList::append(list, RandomAccessList::new(i));
+                    model.push(i);
+                }
+                4 if size > 0 => {
+                    let index = if i % 4 == 0 { size - 1 } else { seed % size };
+                    list = list.update(index, i);
+                    model[index] = i;
+                }
+                5 if size > 0 => {
+                    let index = seed % size;
+                    let count = (seed / 7) % (size - index).min(3) + 1;
+                    list = InmutList::remove_at(list, index, count);
+                    model.drain(index..index + count);
+                }
+                _ => {}
+            }
+
+            assert_eq!(list.size(), model.len());
+            assert!(list.iter().eq(model.iter()));
+            if i % 50 == 0 { versions.push((list.clone(), model.clone())); }
+
+            //Skew binary: complete trees of 2^k - 1 elements, increasing, only the first two may be equal.
+            let sizes = list.tree_sizes();
+            assert_eq!(sizes.iter().sum::<usize>(), model.len());
+            assert!(sizes.iter().all(|size| (size + 1).is_power_of_two()));
+            assert!(sizes.windows(2).enumerate().all(|(k, pair)| pair[0] < pair[1] || (k == 0 && pair[0] == pair[1])));
+        }
+
+        let size = model.len();
+        assert_eq!(list.get(0), model.first());
+        assert_eq!(list.get(size - 1), model.last());
+        assert!(list.get(size).is_none());
+        assert_eq!(list.head(), model.first());
+        for (i, value) in model.iter().enumerate() {
+            assert_eq!(InmutList::value_at(list.clone(), i), *value);
+        }
+        assert!(list.reverse().iter().eq(model.iter().rev()));
+        assert!(list.to_list().iter().eq(model.iter()));
+        assert!(RandomAccessList::from_list(&list.to_list()).iter().eq(model.iter()));
+        assert_versions(&versions, |version| version.iter());
+    }
+
+    #[test]
+    fn bankers_deque() {
+        let mut deq : BankersDeque<u64> = BankersDeque::new();
+        assert!(deq.empty());
+
+        for i in 0..50 {
+            deq = deq.push_back(i);
+        }
+        let full = deq.clone();
+
+        for i in 0..25 {
+            let (value, rest) = deq.pop_front();
+            assert_eq!(value, i);
+            deq = rest;
+        }
+        for i in (25..50).rev() {
+            let (value, rest) = deq.pop_back();
+            assert_eq!(value, i);
+            deq = rest;
+        }
+        assert!(deq.empty());
+
+        //Old versions stay valid.
+        assert_eq!(full.size(), 50);
+        assert_eq!(InmutList::value_at(full.clone(), 49), 49);
+
+        for i in 0..10 {
+            deq = deq.push_front(i);
+        }
+        assert_eq!(format!("{}", deq), "[9,8,7,6,5,4,3,2,1,0]");
+        assert_eq!(format!("{}", deq.reverse()), "[0,1,2,3,4,5,6,7,8,9]");
+        for i in 0..10 {
+            assert_eq!(InmutList::value_at(deq.clone(), i), 9 - i as u64);
+        }
+
+        deq = InmutList::remove_at(deq, 2, 6);
+        deq = InmutList::insert_at(deq, BankersDeque::new().push_back(50), 1);
+        assert_eq!(format!("{}", deq), "[9,50,8,1,0]");
+        assert_eq!(format!("{}", deq.to_list()), "[9,50,8,1,0]");
+    }
+
+    #[test]
+    fn bankers_deque_old_versions() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        //Rotations clone the elements they move, so counting clones measures the work done.
+        static CLONES : AtomicUsize = AtomicUsize::new(0);
+        #[derive(Debug, PartialEq)]
+        struct Counted(u64);
+        impl Clone for Counted {
+            fn clone(&self) -> Self {
+                CLONES.fetch_add(1, Ordering::SeqCst);
+                Counted(self.0)
+            }
+        }
+
+        let mut versions : Vec<BankersDeque<Counted>> = Vec::new();
+        let mut deq = BankersDeque::new();
+        for i in 0..3000 {
+            deq = deq.push_back(Counted(i));
+            versions.push(deq.clone());
+        }
+
+        //Once a version was used, using it again must not redo a rotation.
+        let mut worst = 0;
+        for version in versions.iter() {
+            let (first, _) = version.pop_front();
+            let (last, _) = version.pop_back();
+            for _ in 0..5 {
+                let before = CLONES.load(Ordering::SeqCst);
+                let (value, _) = version.pop_front();
+                assert_eq!(value, first);
+                let (value, _) = version.pop_back();
+                assert_eq!(value, last);
+                worst = cmp::max(worst, CLONES.load(Ordering::SeqCst) - before);
+            }
+        }
+        assert!(worst <= 4, "Popping from an old version cloned {} elements.", worst);
+
+        assert!(deq.iter().map(|value| value.0).eq(0..3000));
+
+        //Long chains of suspensions and cells are forced and dropped without recursion.
+        let mut long : BankersDeque<u64> = BankersDeque::new();
+        for i in 0..200000 {
+            long = long.push_back(i);
+        }
+        let (first, rest) = long.pop_front();
+        assert_eq!(first, 0);
+        assert_eq!(rest.size(), 199999);
+        drop(long);
+        assert_eq!(InmutList::value_at(rest, 100000), 100001);
+    }
+
+    #[test]
+    fn catenable_list() {
+        let mut cat : CatenableList<u64> = CatenableList::new();
+        for i in 0..1000 {
+            let fragment = LinkedNode::cons(LinkedNode::new(2 * i), LinkedNode::new(2 * i + 1));
+            cat = InmutList::append(cat, CatenableList::from_list(fragment));
+        }
+        assert_eq!(cat.size(), 2000);
+
+        let copy = cat.clone();
+        for i in 0..2000 {
+            let (value, tail) = cat.uncons().unwrap();
+            assert_eq!(*value, i);
+            cat = tail;
+        }
+        assert!(cat.is_empty());
+        assert_eq!(copy.iter().count(), 2000);
+        assert_eq!(InmutList::value_at(copy.clone(), 1234), 1234);
+
+    }
+
+    #[test]
+    fn catenable_list_model() {
+        let empty : CatenableList<u64> = CatenableList::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty.size(), 0);
+        assert!(empty.uncons().is_none());
+        assert!(empty.head().is_none());
+        assert!(empty.skip(3).is_empty());
+        assert!(empty.reverse().is_empty());
+        assert!(empty.to_list().is_empty());
+        assert!(InmutList::append(empty.clone(), empty.clone()).is_empty());
+        assert!(CatenableList::<u64>::from_list(LinkedNode::nil()).is_empty());
+
+        let single = CatenableList::from_list(LinkedNode::new(7));
+        assert_eq!(single.head(), Some(&7));
+        assert!(single.uncons().unwrap().1.is_empty());
+        assert!(InmutList::remove_at(single.clone(), 0, 1).is_empty());
+        assert_eq!(format!("{}", InmutList::append(empty.clone(), single.clone())), "[7]");
+        assert_eq!(format!("{}", InmutList::append(single.clone(), empty.clone())), "[7]");
+
+        //Fragments of different sizes, including empty ones, are linked at both ends and in the middle.
+        let fragment = |start : u64, len : u64| {
+            let values : Vec<u64> = (start..start + len).collect();
+            (CatenableList::from_list(LinkedNode::prepend_all(values.clone(), &LinkedNode::nil())), values)
+        };
+        let mut model : Vec<u64> = Vec::new();
+        let mut list : CatenableList<u64> = CatenableList::new();
+        let mut versions : Vec<(CatenableList<u64>, Vec<u64>)> = Vec::new();
+        let mut seed : usize = 11;
+        for i in 0..300 {
+            let seed = lcg(&mut seed);
+            let size = model.len();
+            let (other, values) = fragment(1000 * i, (seed % 4) as u64);
+            //The fragments are shared by the lists they are linked into, they must stay as they are too.
+            if i % 7 == 0 { versions.push((other.clone(), values.clone())); }
+            match i % 5 {
+                0 | 1 => {
+                    list = InmutList::append(list, other);
+                    model.extend(values);
+                }
+                2 => {
+                    list = InmutList::append(other, list);
+                    model.splice(0..0, values);
+                }
+                3 => {
+                    let index = if i % 10 == 3 { size } else { seed % (size + 1) };
+                    list = InmutList::insert_at(list, other, index);
+                    model.splice(index..index, values);
+                }
+                _ if size > 0 => {
+                    let index = seed % size;
+                    let count = (seed / 7) % (size - index).min(3) + 1;
+                    list = InmutList::remove_at(list, index, count);
+                    model.drain(index..index + count);
+                }
+                _ => {}
+            }
+
+            assert_eq!(list.size(), model.len());
+            assert!(list.iter().eq(model.iter()));
+            if i % 50 == 0 { versions.push((list.clone(), model.clone())); }
+        }
+
+        let size = model.len();
+        assert_eq!(list.head(), model.first());
+        assert_eq!(InmutList::value_at(list.clone(), size - 1), *model.last().unwrap());
+        assert!(list.skip(size).is_empty());
+        assert!(list.skip(size - 1).iter().eq(model.last()));
+        let (head, tail) = list.uncons().unwrap();
+        assert_eq!(head, &model[0]);
+        assert!(tail.iter().eq(model[1..].iter()));
+        assert!(list.reverse().iter().eq(model.iter().rev()));
+        assert!(list.to_list().iter().eq(model.iter()));
+        assert_versions(&versions, |version| version.iter());
+    }
+
+    #[test]
+    fn catenable_list_shared_uncons() {
+        let n : u64 = if cfg!(miri) { 100 } else { 20000 };
+        let mut versions : Vec<CatenableList<u64>> = vec![CatenableList::new()];
+        for i in 0..n {
+            let single = CatenableList::from_list(LinkedNode::new(i));
+            versions.push(InmutList::append(versions.last().unwrap().clone(), single));
+        }
+
+        //Each version has its own queue of children, uncons only links the first one of them.
+        for (k, version) in versions.iter().enumerate().skip(1) {
+            let (head, tail) = version.uncons().unwrap();
+            assert_eq!(*head, 0);
+            assert_eq!(tail.size(), k - 1);
+            assert_eq!(tail.head(), if k > 1 { Some(&1) } else { None });
+        }
+
+        //The second walk over the same versions reuses the links memoized by the first one.
+        for version in [versions[n as usize].clone(), versions[n as usize].clone(), versions[n as usize / 2].clone()] {
+            let mut list = version;
+            let mut expected = 0;
+            while let Some((head, tail)) = list.uncons() {
+                assert_eq!(*head, expected);
+                expected += 1;
+                list = tail;
+            }
+            assert!(expected == n || expected == n / 2);
+        }
+        assert_eq!(format!("{}", versions[3]), "[0,1,2]");
+    }
+
+    #[test]
+    fn catenable_list_deep() {
+        let mut cat : CatenableList<u64> = CatenableList::new();
+        for i in 0..100000 {
+            cat = InmutList::append(CatenableList::from_list(LinkedNode::new(i)), cat);
+        }
+        assert_eq!(cat.head(), Some(&99999));
+        assert_eq!(cat.skip(99999).head(), Some(&0));
+    }
+
+    #[test]
+    fn finger_tree() {
+        let mut tree : FingerTree<u64> = FingerTree::new();
+        for i in 0..500 {
+            tree = if i % 2 == 0 { tree.push_back(i) } else { tree.push_front(i) };
+        }
+        let expected : Vec<u64> = (0..500).filter(|i| i % 2 == 1).rev().chain((0..500).filter(|i| i % 2 == 0)).collect();
+
+        assert_eq!(tree.size(), 500);
+        assert!(tree.iter().eq(expected.iter()));
+        for (i, value) in expected.iter().enumerate() {
+            assert_eq!(tree.get(i), Some(value));
+        }
+
+        for i in 0..=500 {
+            let (left, right) = tree.split_at(i);
+            assert_eq!(left.size(), i);
+            assert!(left.concat(&right).iter().eq(expected.iter()));
+        }
+
+        let (first, rest) = tree.pop_front().unwrap();
+        assert_eq!(*first, 499);
+        let (_, last) = rest.pop_back().unwrap();
+        assert_eq!(*last, 498);
+
+    }
+
+    #[test]
+    fn finger_tree_model() {
+        let empty : FingerTree<u64> = FingerTree::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty.size(), 0);
+        assert!(empty.get(0).is_none());
+        assert!(empty.front().is_none() && empty.back().is_none());
+        assert!(empty.pop_front().is_none() && empty.pop_back().is_none());
+        let (left, right) = empty.split_at(0);
+        assert!(left.is_empty() && right.is_empty());
+        assert!(empty.concat(&empty).is_empty());
+        assert!(empty.reverse().is_empty());
+        assert!(empty.to_list().is_empty());
+
+        let single = FingerTree::new().push_back(7);
+        assert_eq!(single.front(), Some(&7));
+        assert_eq!(single.back(), Some(&7));
+        assert!(single.get(1).is_none());
+        assert!(single.pop_front().unwrap().1.is_empty());
+        assert!(single.pop_back().unwrap().0.is_empty());
+        let (left, right) = single.split_at(1);
+        assert_eq!(left.size(), 1);
+        assert!(right.is_empty());
+        assert!(InmutList::remove_at(single.clone(), 0, 1).is_empty());
+
+        //Every operation is checked against a Vec, old versions must keep their contents.
+        let mut model : Vec<u64> = Vec::new();
+        let mut tree : FingerTree<u64> = FingerTree::new();
+        let mut versions : Vec<(FingerTree<u64>, Vec<u64>)> = Vec::new();
+        let mut seed : usize = 3;
+        for i in 0..600 {
+            let seed = lcg(&mut seed);
+            let size = model.len();
+            match i % 8 {
+                0 | 1 => {
+                    tree = tree.push_back(i);
+                    model.push(i);
+                }
+                2 => {
+                    tree = tree.push_front(i);
+                    model.insert(0, i);
+                }
+                3 => {
+                    let index = if i % 16 == 3 { 0 } else if i % 16 == 11 { size } else { seed % (size + 1) };
+                    let values : Vec<u64> = (0..(seed % 5) as u64).map(|j| 1000 * i + j).collect();
+                    let other = values.iter().fold(FingerTree::new(), |tree, value| tree.push_back(*value));
+                    tree = InmutList::insert_at(tree, other, index);
+                    model.splice(index..index, values);
+                }
+                4 if size > 0 => {
+                    let (value, rest) = tree.pop_front().unwrap();
+                    assert_eq!(*value, model.remove(0));
+                    tree = rest;
+                }
+                5 if size > 0 => {
+                    let (rest, value) = tree.pop_back().unwrap();
+                    assert_eq!(Some(*value), model.pop());
+                    tree = rest;
+                }
+                6 if size > 0 => {
+                    let index = seed % size;
+                    let count = (seed / 7) % (size - index).min(4) + 1;
+                    tree = InmutList::remove_at(tree, index, count);
+                    model.drain(index..index + count);
+                }
+                _ => {
+                    let index = seed % (size + 1);
+                    let (left, right) = tree.split_at(index);
+                    assert!(left.iter().eq(model[..index].iter()));
+                    assert!(right.iter().eq(model[index..].iter()));
+                    tree = right.concat(&left);
+                    model.rotate_left(index);
+                }
+            }
+
+            assert_eq!(tree.size(), model.len());
+            assert!(tree.iter().eq(model.iter()));
+            assert_eq!(tree.front(), model.first());
+            assert_eq!(tree.back(), model.last());
+            if i % 60 == 0 { versions.push((tree.clone(), model.clone())); }
+        }
+
+        let size = model.len();
+        for (i, value) in model.iter().enumerate() {
+            assert_eq!(tree.get(i), Some(value));
+        }
+        assert!(tree.get(size).is_none());
+        let (left, right) = tree.split_at(size + 10);
+        assert_eq!(left.size(), size);
+        assert!(right.is_empty());
+        assert!(tree.reverse().iter().eq(model.iter().rev()));
+        assert!(FingerTree::from_list(&tree.to_list()).iter().eq(model.iter()));
+        assert_versions(&versions, |version| version.iter());
+
+        //Every split point, including the ones inside the digits and the deeper levels.
+        for index in 0..=size {
+            let (left, right) = tree.split_at(index);
+            assert_eq!((left.size(), right.size()), (index, size - index));
+            assert_eq!(left.back(), model[..index].last());
+            assert_eq!(right.front(), model.get(index));
+            assert_eq!(right.get(0), model.get(index));
+            assert!(left.concat(&right).iter().eq(model.iter()));
+        }
+    }
+
+    #[test]
+    fn lazy_list() {
+        let naturals : LazyList<u64> = LazyList::iterate(0, |x| x + 1);
+        assert_eq!(format!("{}", naturals.take(5)), "[0,1,2,3,4]");
+
+        let evens = naturals.filter(|x| x % 2 == 0).map(|x| x * 10);
+        assert_eq!(format!("{}", evens.take(4)), "[0,20,40,60]");
+
+        let pairs = naturals.zip(&LazyList::repeat(7));
+        assert_eq!(*pairs.head().unwrap(), (0, 7));
+
+        let llist = LinkedNode::cons(LinkedNode::new(1), LinkedNode::new(2));
+        assert_eq!(format!("{}", LazyList::cycle(llist.clone()).take(5)), "[1,2,1,2,1]");
+        assert_eq!(format!("{}", LazyList::from_list(llist).take(5)), "[1,2]");
+
+        let countdown = LazyList::unfold(3, |n| if *n == 0 { None } else { Some((*n, n - 1)) });
+        assert_eq!(format!("{}", countdown.take(10)), "[3,2,1]");
+
+        //Cells are evaluated once and shared between clones.
+        let calls = Rc::new(core::cell::Cell::new(0));
+        let counter = calls.clone();
+        let counted = naturals.map(move |x| { counter.set(counter.get() + 1); *x });
+        let copy = counted.clone();
+        counted.take(10);
+        copy.take(10);
+        assert_eq!(calls.get(), 10);
+
+        assert_eq!(naturals.iter().nth(200000), Some(200000));
+    }
+
+    #[test]
+    fn list_zipper() {
+        let mut llist : Rc<LinkedNode<u64>> = LinkedNode::nil();
+        for i in 1..6 {
+            llist = InmutList::append(llist, LinkedNode::new(i));
+        }
+
+        let start = ListZipper::new(llist.clone());
+        assert!(start.is_start());
+        assert!(start.left().is_none());
+        assert_eq!(start.focus(), Some(&1));
+
+        let mut zipper = start.right().unwrap().right().unwrap();
+        assert_eq!(zipper.index(), 2);
+        assert_eq!(zipper.focus(), Some(&3));
+
+        zipper = zipper.replace(30).unwrap();
+        zipper = zipper.insert(25);
+        assert_eq!(format!("{}", zipper), "[1,2,25,30,4,5]");
+
+        zipper = zipper.left().unwrap().delete().unwrap();
+        assert_eq!(zipper.focus(), Some(&25));
+        assert_eq!(format!("{}", zipper.to_list()), "[1,25,30,4,5]");
+
+        while let Some(next) = zipper.right() {
+            zipper = next;
+        }
+        assert!(zipper.is_end());
+        assert!(zipper.delete().is_none());
+        assert_eq!(format!("{}", zipper.insert(6)), "[1,25,30,4,5,6]");
+
+        //The original list is untouched.
+        assert_eq!(format!("{}", llist), "[1,2,3,4,5]");
+    }
+
+    #[test]
+    fn insert_all_at() {
+        let mut dllist : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        assert_eq!(dllist.insert_all_at(0, Vec::new()), 0);
+        assert_eq!(dllist.insert_all_at(0, vec![1, 2]), 2);
+        assert_eq!(format!("{}", dllist), "[1,2]");
+
+        dllist.insert_all_at(0, vec![10, 20]);
+        assert_eq!(format!("{}", dllist), "[10,20,1,2]");
+        dllist.insert_all_at(2, 30..33);
+        assert_eq!(format!("{}", dllist), "[10,20,30,31,32,1,2]");
+        dllist.insert_all_at(7, vec![5]);
+        assert_eq!(format!("{}", dllist), "[10,20,30,31,32,1,2,5]");
+        assert_eq!(dllist.size(), 8);
+        assert_eq!(format!("{}", dllist.reverse()), "[5,2,1,32,31,30,20,10]");
+
+        let mut llist : Rc<LinkedNode<u64>> = LinkedNode::nil();
+        llist = llist.insert_all_at(0, vec![1, 2]);
+        let inserted = llist.insert_all_at(1, 10..13);
+        assert_eq!(format!("{}", inserted), "[1,10,11,12,2]");
+        assert_eq!(format!("{}", inserted.insert_all_at(5, vec![3])), "[1,10,11,12,2,3]");
+        assert_eq!(format!("{}", llist), "[1,2]");
+    }
+
+    #[test]
+    fn dllist_drain() {
+        let mut dllist : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        dllist.insert_all_at(0, 0..10);
+
+        let drained : Vec<u64> = dllist.drain(2..5).collect();
+        assert_eq!(drained, vec![2, 3, 4]);
+        assert_eq!(format!("{}", dllist), "[0,1,5,6,7,8,9]");
+
+        assert_eq!(dllist.remove_range(..=1), 2);
+        assert_eq!(format!("{}", dllist), "[5,6,7,8,9]");
+
+        //Elements that aren't consumed are removed too.
+        let mut drain = dllist.drain(1..3);
+        assert_eq!(drain.next(), Some(6));
+        drop(drain);
+        assert_eq!(format!("{}", dllist), "[5,8,9]");
+        assert_eq!(format!("{}", dllist.reverse()), "[9,8,5]");
+
+        dllist.truncate(5);
+        assert_eq!(dllist.size(), 3);
+        dllist.truncate(1);
+        assert_eq!(format!("{}", dllist), "[9]");
+
+        dllist.insert_all_at(1, 0..3);
+        dllist.clear();
+        assert!(dllist.empty());
+        dllist.append(1);
+        assert_eq!(format!("{}", dllist), "[1]");
+
+        //Removed nodes are freed.
+        let value = Rc::new(0);
+        let mut shared : DoublyLinkedList<Rc<u64>> = DoublyLinkedList::new();
+        shared.insert_all_at(0, (0..5).map(|_| value.clone()));
+        shared.remove_range(1..4);
+        assert_eq!(Rc::strong_count(&value), 3);
+        drop(shared);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn search() {
+        let mut dllist : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        dllist.insert_all_at(0, vec![1, 3, 5, 7, 3, 9]);
+
+        assert!(dllist.contains(&7));
+        assert!(!dllist.contains(&4));
+        assert_eq!(dllist.index_of(&3), Some(1));
+        assert_eq!(dllist.position(|x| *x > 4), Some(2));
+        assert_eq!(dllist.rposition(|x| *x == 3), Some(4));
+        assert_eq!(dllist.rposition(|x| *x == 4), None);
+        assert_eq!(dllist.find(|x| *x > 5), Some(&7));
+
+        *dllist.find_mut(|x| *x == 9).unwrap() = 10;
+        assert_eq!(format!("{}", dllist), "[1,3,5,7,3,10]");
+
+        let mut llist : Rc<LinkedNode<u64>> = LinkedNode::nil();
+        llist = llist.insert_all_at(0, vec![1, 3, 5, 7, 3, 9]);
+        assert!(llist.contains(&9));
+        assert_eq!(llist.index_of(&3), Some(1));
+        assert_eq!(llist.position(|x| *x > 4), Some(2));
+        assert_eq!(llist.rposition(|x| *x == 3), Some(4));
+        assert_eq!(llist.find(|x| *x > 7), Some(&9));
+
+        let mut sorted : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        sorted.insert_all_at(0, (0..20).map(|x| x * 2));
+        let sorted_llist = LinkedNode::nil().insert_all_at(0, (0..20).map(|x| x * 2));
+        for i in 0..40 {
+            let expected = if i % 2 == 0 { Ok(i as usize / 2) } else { Err(i as usize / 2 + 1) };
+            assert_eq!(sorted.binary_search_by(|x| x.cmp(&i)), expected);
+            assert_eq!(sorted_llist.binary_search_by(|x| x.cmp(&i)), expected);
+        }
+
+        let mut comparisons = 0;
+        sorted.binary_search_by(|x| { comparisons += 1; x.cmp(&21) }).unwrap_err();
+        assert!(comparisons <= 5);
+    }
+
+    #[test]
+    fn dllist_move_and_swap() {
+        let mut dllist : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        dllist.insert_all_at(0, 0..6);
+
+        dllist.move_to_front(dllist.at_as_ref(3).clone());
+        assert_eq!(format!("{}", dllist), "[3,0,1,2,4,5]");
+        dllist.move_to_back(dllist.at_as_ref(0).clone());
+        assert_eq!(format!("{}", dllist), "[0,1,2,4,5,3]");
+        dllist.move_to_back(dllist.at_as_ref(2).clone());
+        assert_eq!(format!("{}", dllist), "[0,1,4,5,3,2]");
+
+        let node = dllist.at(0);
+        let target = dllist.at(4);
+        dllist.move_before(node, target);
+        assert_eq!(format!("{}", dllist), "[1,4,5,0,3,2]");
+
+        dllist.swap(0, 5);
+        assert_eq!(format!("{}", dllist), "[2,4,5,0,3,1]");
+        dllist.swap(1, 2);
+        assert_eq!(format!("{}", dllist), "[2,5,4,0,3,1]");
+        dllist.swap(4, 3);
+        assert_eq!(format!("{}", dllist), "[2,5,4,3,0,1]");
+        dllist.swap(5, 0);
+        assert_eq!(format!("{}", dllist), "[1,5,4,3,0,2]");
+
+        //prev links are kept consistent.
+        assert_eq!(format!("{}", dllist.reverse()), "[2,0,3,4,5,1]");
+
+        let mut pair : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        pair.insert_all_at(0, 0..2);
+        pair.swap(0, 1);
+        assert_eq!(format!("{}", pair), "[1,0]");
+        pair.move_to_back(pair.at_as_ref(1).clone());
+        assert_eq!(format!("{}", pair), "[1,0]");
+    }
+
+    #[test]
+    fn dllist_move_last_to_front() {
+        let mut dllist : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        dllist.insert_all_at(0, 0..4);
+        let last = dllist.at(3);
+        dllist.move_to_front(last);
+        assert_eq!(format!("{}", dllist), "[3,0,1,2]");
+        assert_eq!(dllist.pop_back(), 2);
+        assert_eq!(dllist.pop_front(), 3);
+
+        let mut dllist : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        dllist.insert_all_at(0, 0..4);
+        let (node, target) = (dllist.at(3), dllist.at(0));
+        dllist.move_before(node, target);
+        assert_eq!(format!("{}", dllist), "[3,0,1,2]");
+        assert_eq!(format!("{}", dllist.reverse()), "[2,1,0,3]");
+
+        //Moving the head to the front and a node before its own successor change nothing.
+        let head = dllist.at(0);
+        dllist.move_to_front(head);
+        let (node, target) = (dllist.at(1), dllist.at(2));
+        dllist.move_before(node, target);
+        assert_eq!(format!("{}", dllist), "[2,1,0,3]");
+
+        let mut single : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        single.append(7);
+        let node = single.at(0);
+        single.move_to_front(node.clone());
+        single.move_to_back(node);
+        assert_eq!(format!("{}", single), "[7]");
+    }
+
+    #[test]
+    fn sorted_merge_and_set_operations() {
+        let mut a : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        a.insert_all_at(0, vec![1, 3, 5, 7]);
+        let mut b : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        b.insert_all_at(0, vec![0, 3, 4, 8, 9]);
+
+        let c = LinkedNode::nil().insert_all_at(0, vec![3, 4, 5]);
+        fn values<'a, I : Iterator<Item = &'a u64>>(iter : I) -> String {
+            iter.map(|x| x.to_string()).collect::<Vec<_>>().join(",")
+        }
+
+        assert_eq!(values(set_ops::union(&a, &b)), "0,1,3,4,5,7,8,9");
+        assert_eq!(values(set_ops::intersection(&a, c.iter())), "3,5");
+        assert_eq!(values(set_ops::difference(&a, &b)), "1,5,7");
+        assert_eq!(values(set_ops::symmetric_difference(c.iter(), &b)), "0,5,8,9");
+
+        a.merge(b);
+        assert_eq!(format!("{}", a), "[0,1,3,3,4,5,7,8,9]");
+        assert_eq!(a.size(), 9);
+        assert_eq!(format!("{}", a.reverse()), "[9,8,7,5,4,3,3,1,0]");
+
+        let mut empty : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        let mut small : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        small.insert_all_at(0, vec![2, 6]);
+        empty.merge(small);
+        empty.merge(DoublyLinkedList::new());
+        let mut low : DoublyLinkedList<u64> = DoublyLinkedList::new();
+        low.insert_all_at(0, vec![0, 1, 7]);
+        empty.merge(low);
+        assert_eq!(format!("{}", empty), "[0,1,2,6,7]");
+        assert!(empty.iter().eq([0, 1, 2, 6, 7].iter()));
+    }
+
+    #[test]
+    fn sorted_list() {
+        let mut sorted : SortedList<u64> = SortedList::new();
+        for value in [5, 1, 4, 1, 9, 2, 6] {
+            assert_eq!(sorted.insert(value), 0);
+        }
+        assert_eq!(format!("{}", sorted), "[1,1,2,4,5,6,9]");
+        assert_eq!(sorted.first(), Some(&1));
+        assert_eq!(sorted.last(), Some(&9));
+        assert!(sorted.contains(&6));
+        assert!(!sorted.contains(&3));
+
+        let in_range : Vec<u64> = sorted.range(2..6).cloned().collect();
+        assert_eq!(in_range, vec![2, 4, 5]);
+        let in_range : Vec<u64> = sorted.range(..=1).cloned().collect();
+        assert_eq!(in_range, vec![1, 1]);
+
+        assert!(sorted.remove(&1));
+        assert!(!sorted.remove(&3));
+        assert_eq!(format!("{}", sorted), "[1,2,4,5,6,9]");
+
+        assert_eq!(sorted.pop_front(), 1);
+        assert_eq!(sorted.pop_back(), 9);
+        assert_eq!(sorted.size(), 4);
+
+        let mut unique : SortedList<u64> = SortedList::with_policy(DuplicatePolicy::Reject);
+        assert_eq!(unique.insert(3), 0);
+        assert_eq!(unique.insert(3), 1);
+        assert_eq!(unique.insert(2), 0);
+        assert_eq!(format!("{}", unique), "[2,3]");
+
+        let mut replacing : SortedList<String> = SortedList::with_policy(DuplicatePolicy::Replace);
+        replacing.insert(String::from("b"));
+        replacing.insert(String::from("a"));
+        assert_eq!(replacing.insert(String::from("b")), 0);
+        assert_eq!(format!("{}", replacing), "[a,b]");
+    }
+
+    #[test]
+    fn skip_list() {
+        let mut skip : SkipList<u64> = SkipList::new();
+        let mut dllist : DoublyLinkedList<u64> = DoublyLinkedList::new();
+
+        for i in 0..300 {
+            let index = (i * 7919) % (skip.size() + 1);
+            skip.insert_at(i as u64, index);
+            dllist.insert_at(i as u64, index);
+        }
+        assert_eq!(skip.size(), 300);
+        assert!(skip.iter().eq(dllist.iter()));
+        for i in 0..300 {
+            assert_eq!(skip.value_at(i), dllist.value_at(i));
+        }
+
+        for i in 0..150 {
+            let index = (i * 104729) % skip.size();
+            assert_eq!(skip.remove_at(index), 0);
+            dllist.remove_at(index);
+        }
+        assert!(skip.iter().eq(dllist.iter()));
+        for i in 0..150 {
+            assert_eq!(skip.value_at(i), dllist.value_at(i));
+        }
+
+        *skip.mut_value_at(3) = 1000;
+        assert_eq!(*skip.value_at(3), 1000);
+        assert_eq!(skip.remove_at(150), 2);
+
+        let mut set : SkipList<u64> = SkipList::new_ordered();
+        for value in [5, 1, 4, 1, 9, 2, 6] {
+            set.insert_ordered(value);
+        }
+        assert_eq!(format!("{}", set), "[1,2,4,5,6,9]");
+        assert_eq!(set.append(3), 1);
+        assert_eq!(set.index_of(&6), Some(4));
+        assert!(set.remove_value(&4));
+        assert!(!set.contains(&4));
+        assert_eq!(format!("{}", set), "[1,2,5,6,9]");
+    }
+
+    #[test]
+    fn unrolled_list() {
+        let mut unrolled : UnrolledList<u64, 4> = UnrolledList::new();
+        let mut model : Vec<u64> = Vec::new();
+
+        for i in 0..200 {
+            let index = (i * 7919) % (model.len() + 1);
+            unrolled.insert_at(i as u64, index);
+            model.insert(index, i as u64);
+        }
+        assert!(unrolled.iter().eq(model.iter()));
+        assert!(unrolled.node_count() <= 100);
+
+        for i in 0..150 {
+            let index = (i * 104729) % model.len();
+            assert_eq!(unrolled.remove_at(index), 0);
+            model.remove(index);
+        }
+        assert!(unrolled.iter().eq(model.iter()));
+        for (i, value) in model.iter().enumerate() {
+            assert_eq!(unrolled.value_at(i), value);
+        }
+        assert_eq!(unrolled.remove_at(50), 2);
+
+        unrolled.reverse();
+        model.reverse();
+        assert!(unrolled.iter().eq(model.iter()));
+
+        unrolled.push_front(500).push_back(600);
+        assert_eq!(unrolled.pop_front(), 500);
+        assert_eq!(unrolled.pop_back(), 600);
+        while !unrolled.empty() {
+            assert_eq!(unrolled.pop_back(), model.pop().unwrap());
+        }
+        assert_eq!(unrolled.node_count(), 0);
+
+        let mut strings : UnrolledList<String> = UnrolledList::default();
+        strings.append(String::from("a"));
+        strings.append(String::from("b"));
+        *strings.mut_value_at(1) = String::from("c");
+        assert_eq!(format!("{}", strings.clone()), "[a,c]");
+    }
+
+    #[test]
+    fn rope() {
+        let mut rope : Rope<u64> = Rope::new();
+        let mut model : Vec<u64> = Vec::new();
+
+        for i in 0..500 {
+            let index = (i * 7919) % (model.len() + 1);
+            rope.insert_at(i as u64, index);
+            model.insert(index, i as u64);
+            if i % 100 == 0 {
+                rope.reverse();
+                model.reverse();
+            }
+        }
+        assert!(rope.iter().eq(model.iter()));
+
+        for i in 0..200 {
+            let index = (i * 104729) % model.len();
+            assert_eq!(rope.remove_at(index), 0);
+            model.remove(index);
+        }
+        rope.reverse();
+        model.reverse();
+        for (i, value) in model.iter().enumerate() {
+            assert_eq!(rope.value_at(i), value);
+        }
+        *rope.mut_value_at(10) = 1000;
+        model[10] = 1000;
+
+        let mut tail = rope.split_off(120);
+        assert_eq!(rope.size(), 120);
+        assert!(tail.iter().eq(model[120..].iter()));
+        tail.reverse();
+        rope.concat(tail);
+        model[120..].reverse();
+        assert!(rope.iter().eq(model.iter()));
+        assert_eq!(rope.remove_at(model.len()), 2);
+
+        let mut small : Rope<u64> = Rope::default();
+        small.append(1);
+        small.append(2);
+        small.append(3);
+        small.reverse();
+        assert_eq!(format!("{}", small), "[3,2,1]");
+    }
+
+    #[test]
+    fn xor_list() {
+        let mut xor_list : XorList<String> = XorList::new();
+        let mut model : VecDeque<String> = VecDeque::new();
+
+        for i in 0..100 {
+            if i % 3 == 0 {
+                xor_list.push_front(i.to_string());
+                model.push_front(i.to_string());
+            } else {
+                xor_list.push_back(i.to_string());
+                model.push_back(i.to_string());
+            }
+            if i % 7 == 0 {
+                assert_eq!(xor_list.pop_front(), model.pop_front().unwrap());
+            }
+            if i % 11 == 5 {
+                assert_eq!(xor_list.pop_back(), model.pop_back().unwrap());
+            }
+        }
+        assert_eq!(xor_list.size(), model.len());
+        assert!(xor_list.iter().eq(model.iter()));
+        assert!(xor_list.iter().rev().eq(model.iter().rev()));
+
+        let mut both_ends = xor_list.iter();
+        assert_eq!(both_ends.next(), model.front());
+        assert_eq!(both_ends.next_back(), model.back());
+        assert_eq!(both_ends.count(), model.len() - 2);
+
+        xor_list.reverse();
+        assert!(xor_list.iter().eq(model.iter().rev()));
+        assert_eq!(xor_list.front(), model.back());
+        xor_list.push_back(String::from("end"));
+        assert_eq!(xor_list.back().unwrap(), "end");
+
+        let copy = xor_list.clone();
+        xor_list.clear();
+        assert!(xor_list.empty());
+        assert_eq!(copy.size(), model.len() + 1);
+
+        let mut small : XorList<u64> = XorList::default();
+        small.push_back(2).push_front(1).push_back(3);
+        assert_eq!(format!("{}", small), "[1,2,3]");
+    }
+
+    struct Task {
+        id : u32,
+        all : Link,
+        ready : Link,
+    }
+
+    impl Task {
+        fn new(id : u32) -> Task {
+            Task { id, all : Link::new(), ready : Link::new() }
+        }
+    }
+
+    impl core::fmt::Display for Task {
+        fn fmt(&self, f : &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(f, "{}", self.id)
+        }
+    }
+
+    crate::intrusive_adapter!(AllAdapter = Task { all });
+    crate::intrusive_adapter!(ReadyAdapter = Task { ready });
+
+    #[test]
+    fn intrusive_list() {
+        let boxed : Vec<core::pin::Pin<Box<Task>>> = (0..5).map(|id| Box::pin(Task::new(id))).collect();
+        let on_stack = pin!(Task::new(5));
+        let on_stack = on_stack.into_ref();
+
+        let mut all : IntrusiveList<AllAdapter> = IntrusiveList::new();
+        let mut ready : IntrusiveList<ReadyAdapter> = IntrusiveList::default();
+        for task in boxed.iter() {
+            assert_eq!(all.push_back(task.as_ref()), 0);
+        }
+        assert_eq!(all.push_front(on_stack), 0);
+        assert_eq!(all.push_back(boxed[0].as_ref()), 1);
+
+        ready.push_back(boxed[3].as_ref());
+        ready.push_back(on_stack);
+        ready.push_front(boxed[1].as_ref());
+        assert_eq!(format!("{}", all), "[5,0,1,2,3,4]");
+        assert_eq!(format!("{}", ready), "[1,3,5]");
+
+        assert!(all.remove(&boxed[3]));
+        assert!(!all.remove(&boxed[3]));
+        assert!(ready.contains(&boxed[3]));
+        assert!(!all.contains(&boxed[3]));
+        assert_eq!(format!("{}", all), "[5,0,1,2,4]");
+
+        assert_eq!(ready.pop_front().unwrap().id, 1);
+        assert_eq!(all.pop_back().unwrap().id, 4);
+        assert_eq!(all.iter().map(|task| task.id).sum::<u32>(), 8);
+        assert_eq!(ready.size(), 2);
+
+        {
+            let mut other : IntrusiveList<AllAdapter> = IntrusiveList::new();
+            assert_eq!(other.push_back(boxed[3].as_ref()), 0);
+            assert!(!other.remove(&boxed[0]));
+        }
+        assert!(!boxed[3].all.is_linked());
+
+        all.clear();
+        assert!(all.empty());
+        assert!(!on_stack.all.is_linked());
+        assert!(on_stack.ready.is_linked());
+        assert_eq!(ready.pop_back().unwrap().id, 5);
+    }
+
+    #[test]
+    fn static_list() {
+        let mut list : StaticList<String, 8> = StaticList::new();
+        let mut model : Vec<String> = Vec::new();
+
+        for i in 0..8 {
+            let index = (i * 5) % (model.len() + 1);
+            assert_eq!(list.try_insert_at(i.to_string(), index), Ok(()));
+            model.insert(index, i.to_string());
+        }
+        assert!(list.is_full());
+        assert_eq!(list.try_push_back(String::from("x")), Err(String::from("x")));
+        assert_eq!(list.append(String::from("x")), 1);
+        assert_eq!(list.insert_at(String::from("x"), 3), 1);
+        assert!(list.iter().eq(model.iter()));
+        assert!(list.iter().rev().eq(model.iter().rev()));
+
+        assert_eq!(list.remove_at(2), 0);
+        model.remove(2);
+        assert_eq!(list.pop_front(), model.remove(0));
+        assert_eq!(list.pop_back(), model.pop().unwrap());
+        list.push_front(String::from("a")).push_back(String::from("z"));
+        model.insert(0, String::from("a"));
+        model.push(String::from("z"));
+        *list.mut_value_at(1) = String::from("b");
+        model[1] = String::from("b");
+        for (i, value) in model.iter().enumerate() {
+            assert_eq!(list.value_at(i), value);
+        }
+
+        list.reverse();
+        model.reverse();
+        assert!(list.iter().eq(model.iter()));
+        assert_eq!(list.remove_at(list.size()), 2);
+
+        let mut small : StaticList<u64, 3> = StaticList::default();
+        small.append(1);
+        small.append(2);
+        let copy = small.clone();
+        small.clear();
+        assert!(small.empty());
+        assert_eq!(small.capacity(), 3);
+        assert_eq!(format!("{}", copy), "[1,2]");
+    }
+
+    #[test]
+    fn bounded_deque() {
+        let mut window : BoundedDeque<u64> = BoundedDeque::with_capacity_limit(3);
+        for i in 0..10 {
+            window.push_back(i);
+        }
+        assert!(window.is_full());
+        assert_eq!(format!("{}", window), "[7,8,9]");
+        window.push_front(100);
+        assert_eq!(format!("{}", window), "[100,7,8]");
+        assert_eq!(window.pop_back(), 8);
+        assert_eq!(window.front(), Some(&100));
+
+        let mut newest : BoundedDeque<u64> = BoundedDeque::with_policy(2, OverflowPolicy::DropNewest);
+        newest.push_back(1).push_back(2).push_back(3).push_front(0);
+        assert_eq!(newest.try_push_back(4), Err(4));
+        assert_eq!(format!("{}", newest), "[1,2]");
+
+        let mut reject : BoundedDeque<u64> = BoundedDeque::with_policy(1, OverflowPolicy::Reject);
+        assert_eq!(reject.try_push_back(1), Ok(()));
+        assert_eq!(reject.try_push_front(2), Err(2));
+        reject.set_policy(OverflowPolicy::DropOldest);
+        reject.push_back(3);
+        assert_eq!(reject.back(), Some(&3));
+
+        let evicted = Rc::new(core::cell::RefCell::new(Vec::new()));
+        let sink = evicted.clone();
+        let mut logged : BoundedDeque<u64> = BoundedDeque::with_policy(2,
+            OverflowPolicy::Callback(Box::new(move |value| sink.borrow_mut().push(value))));
+        for i in 0..5 {
+            logged.push_back(i);
+        }
+        logged.push_front(10);
+        assert_eq!(*evicted.borrow(), vec![0, 1, 2, 4]);
+        assert_eq!(format!("{}", logged), "[10,3]");
+
+        let mut empty : BoundedDeque<u64> = BoundedDeque::with_capacity_limit(0);
+        empty.push_back(1);
+        assert!(empty.empty());
+    }
+
+    #[test]
+    fn bounded_deque_large_front() {
+        //Every push walks no nodes, so filling a big window from the front stays linear.
+        let mut window : BoundedDeque<u64> = BoundedDeque::with_capacity_limit(100000);
+        for i in 0..300000 {
+            window.push_front(i);
+        }
+        assert_eq!(window.size(), 100000);
+        assert_eq!(window.front(), Some(&299999));
+        assert_eq!(window.back(), Some(&200000));
+        assert!(window.iter().copied().eq((200000..300000).rev()));
+
+        for i in 0..1000 {
+            assert_eq!(window.try_push_front(i), Ok(()));
+        }
+        assert_eq!(window.front(), Some(&999));
+        assert_eq!(window.pop_back(), 201000);
+    }
+
+    #[test]
+    fn bounded_deque_zero_limit() {
+        //No policy can store anything, so every push hands the element back.
+        let evicted = Rc::new(core::cell::RefCell::new(Vec::new()));
+        let sink = evicted.clone();
+        let policies : Vec<OverflowPolicy<u64>> = vec![OverflowPolicy::DropOldest, OverflowPolicy::DropNewest,
+            OverflowPolicy::Reject, OverflowPolicy::Callback(Box::new(move |value| sink.borrow_mut().push(value)))];
+
+        for policy in policies {
+            let mut window : BoundedDeque<u64> = BoundedDeque::with_policy(0, policy);
+            assert!(window.is_full());
+            assert_eq!(window.try_push_back(1), Err(1));
+            assert_eq!(window.try_push_front(2), Err(2));
+            assert!(window.empty());
+            assert_eq!(window.front(), None);
+        }
+        assert!(evicted.borrow().is_empty());
+
+        let mut oldest : BoundedDeque<u64> = BoundedDeque::with_capacity_limit(0);
+        oldest.push_back(1).push_front(2);
+        assert_eq!(format!("{}", oldest), "[]");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn blocking_deque_send_values() {
+        use core::cell::Cell;
+        use std::thread;
+
+        //Cell is Send but not Sync, the queue only moves values between threads.
+        struct Fifo<T>(VecDeque<T>);
+        impl<T> Deque<T> for Fifo<T> {
+            fn empty(&self) -> bool { self.0.is_empty() }
+            fn push_back(&mut self, value : T) -> &mut Self { self.0.push_back(value); self }
+            fn pop_back(&mut self) -> T { self.0.pop_back().unwrap() }
+            fn push_front(&mut self, value : T) -> &mut Self { self.0.push_front(value); self }
+            fn pop_front(&mut self) -> T { self.0.pop_front().unwrap() }
+        }
+        fn assert_sync<S : Sync>(_ : &S) {}
+
+        let queue : BlockingDeque<Cell<i32>, Fifo<Cell<i32>>> = BlockingDeque::new(Fifo(VecDeque::new()));
+        assert_sync(&queue);
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                for i in 0..100 {
+                    queue.push_back(Cell::new(i)).unwrap();
+                }
+                queue.close();
+            });
+            let mut sum = 0;
+            while let Some(value) = queue.pop_front() {
+                value.set(value.get() * 2);
+                sum += value.get();
+            }
+            assert_eq!(sum, 9900);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn blocking_deque() {
+        use std::sync::Arc;
+        use std::thread;
+        use std::time::Duration;
+
+        let queue : Arc<BlockingDeque<u64, XorList<u64>>> = Arc::new(BlockingDeque::with_capacity(XorList::new(), 4));
+
+        let producers : Vec<_> = (0..4).map(|p| {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                for i in 0..250 {
+                    queue.push_back(p * 1000 + i).unwrap();
+                }
+            })
+        }).collect();
+        let consumers : Vec<_> = (0..3).map(|_| {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                let mut popped = Vec::new();
+                while let Some(value) = queue.pop_front() {
+                    assert!(queue.size() <= 4);
+                    popped.push(value);
+                }
+                popped
+            })
+        }).collect();
+
+        for producer in producers {
+            producer.join().unwrap();
+        }
+        queue.close();
+        let mut popped : Vec<u64> = consumers.into_iter().flat_map(|consumer| consumer.join().unwrap()).collect();
+        popped.sort();
+        let mut expected : Vec<u64> = (0..4).flat_map(|p| (0..250).map(move |i| p * 1000 + i)).collect();
+        expected.sort();
+        assert_eq!(popped, expected);
+        assert_eq!(queue.push_back(1), Err(1));
+
+        let queue : BlockingDeque<u64, XorList<u64>> = BlockingDeque::default();
+        assert_eq!(queue.try_pop(), None);
+        assert_eq!(queue.pop_front_timeout(Duration::from_millis(10)), None);
+        queue.push_back(2).unwrap();
+        queue.push_front(1).unwrap();
+        assert_eq!(queue.pop_front_timeout(Duration::from_millis(10)), Some(1));
+        assert_eq!(queue.try_pop(), Some(2));
+
+        let queue : Arc<BlockingDeque<u64, XorList<u64>>> = Arc::new(BlockingDeque::new(XorList::new()));
+        let waiter = {
+            let queue = queue.clone();
+            thread::spawn(move || queue.pop_front())
+        };
+        thread::sleep(Duration::from_millis(20));
+        queue.close();
+        assert_eq!(waiter.join().unwrap(), None);
+        assert!(queue.is_closed());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn work_stealing_deque() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::thread;
+
+        let total : u64 = if cfg!(miri) { 200 } else { 100_000 };
+        let mut worker : Worker<Box<u64>> = Worker::new();
+        let done = Arc::new(AtomicBool::new(false));
+
+        let thieves : Vec<_> = (0..6).map(|_| {
+            let stealer = worker.stealer();
+            let done = done.clone();
+            thread::spawn(move || {
+                let mut stolen = Vec::new();
+                loop {
+                    match stealer.pop_front() {
+                        Some(value) => stolen.push(*value),
+                        None if done.load(Ordering::SeqCst) && stealer.empty() => break,
+                        None => thread::yield_now(),
+                    }
+                }
+                stolen
+            })
+        }).collect();
+
+        let mut kept = Vec::new();
+        for i in 0..total {
+            worker.push_back(Box::new(i));
+            if i % 3 == 0 {
+                if let Some(value) = worker.pop_back() {
+                    kept.push(*value);
+                }
+            }
+        }
+        done.store(true, Ordering::SeqCst);
+
+        let mut all : Vec<u64> = thieves.into_iter().flat_map(|thief| thief.join().unwrap()).collect();
+        all.extend(kept);
+        while let Some(value) = worker.pop_back() {
+            all.push(*value);
+        }
+        all.sort();
+        assert!(all.into_iter().eq(0..total));
+
+        let mut owner : Worker<String> = Worker::default();
+        owner.push_back(String::from("a")).push_back(String::from("b")).push_back(String::from("c"));
+        assert_eq!(owner.size(), 3);
+        assert_eq!(owner.pop_front().unwrap(), "a");
+        assert_eq!(owner.pop_back().unwrap(), "c");
+        let stealer = owner.stealer();
+        drop(owner);
+        assert_eq!(stealer.clone().pop_front().unwrap(), "b");
+        assert_eq!(stealer.pop_front(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn work_stealing_reclaims_under_contention() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::thread;
+
+        let thieves = 4;
+        let total : u64 = if cfg!(miri) { 300 } else { 1 << 16 };
+        let mut worker : Worker<u64> = Worker::new();
+        let done = Arc::new(AtomicBool::new(false));
+
+        //The owner tracks every time the buffer grows and how many buffers wait to be freed then.
+        let mut grown = 0;
+        let mut most_retired = 0;
+        let mut push = |worker : &mut Worker<u64>, value| {
+            let capacity = worker.capacity();
+            worker.push_back(value);
+            if worker.capacity() > capacity {
+                grown += 1;
+                most_retired = cmp::max(most_retired, worker.retired());
+            }
+        };
+
+        //One thief stays stuck on the first buffer, filled past its capacity so it gets retired.
+        let stuck = worker.stealer();
+        let release = stuck.hold_buffer();
+        let first = worker.capacity() as u64 + 1;
+        for i in 0..first {
+            push(&mut worker, i);
+        }
+
+        //The others never stop stealing, the owner pushes faster so the buffer grows while they work.
+        let handles : Vec<_> = (0..thieves).map(|_| {
+            let stealer = worker.stealer();
+            let done = done.clone();
+            thread::spawn(move || {
+                let mut stolen = Vec::new();
+                while !done.load(Ordering::SeqCst) {
+                    stolen.extend(stealer.pop_front());
+                }
+                stolen
+            })
+        }).collect();
+        for i in first..total {
+            push(&mut worker, i);
+        }
+        done.store(true, Ordering::SeqCst);
+
+        let mut all : Vec<u64> = handles.into_iter().flat_map(|thief| thief.join().unwrap()).collect();
+        while let Some(value) = worker.pop_back() {
+            all.push(value);
+        }
+        all.sort();
+        assert!(all.into_iter().eq(0..total));
+
+        //Each thief protects one buffer at most, every other retired buffer was freed meanwhile.
+        //Under Miri the thieves can keep up with the owner, so the buffer may only grow once.
+        assert!(grown >= if cfg!(miri) { 1 } else { 3 }, "The buffer only grew {} times.", grown);
+        assert!(most_retired <= thieves + 1, "{} buffers were still retired.", most_retired);
+
+        //Only the buffer of the stuck thief is left, and it's freed once it lets go.
+        worker.push_back(0);
+        assert_eq!(worker.retired(), 1);
+        release();
+        worker.push_back(0);
+        assert_eq!(worker.retired(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn concurrent_deque_traits() {
+        use std::thread;
+
+        fn fill<D : OwnedDeque<u64>>(deque : &mut D, values : core::ops::Range<u64>) {
+            for value in values {
+                deque.push_back(value);
+            }
+        }
+
+        fn take_all<D : ConcurrentDeque<u64>>(deque : &D) -> Vec<u64> {
+            let mut taken = Vec::new();
+            while let Some(value) = deque.pop_front() {
+                taken.push(value);
+            }
+            taken
+        }
+
+        let total : u64 = if cfg!(miri) { 100 } else { 10_000 };
+        let mut worker : Worker<u64> = Worker::new();
+        assert!(ConcurrentDeque::empty(&worker));
+        assert_eq!(OwnedDeque::pop_back(&mut worker), None);
+        assert_eq!(ConcurrentDeque::pop_front(&worker), None);
+
+        fill(&mut worker, 0..total);
+        assert_eq!(OwnedDeque::pop_back(&mut worker), Some(total - 1));
+        assert_eq!(ConcurrentDeque::pop_front(&worker), Some(0));
+
+        let stealers : Vec<Stealer<u64>> = (0..4).map(|_| worker.stealer()).collect();
+        let mut all = thread::scope(|scope| {
+            let thieves : Vec<_> = stealers.iter().map(|stealer| scope.spawn(|| take_all(stealer))).collect();
+            let mut all = take_all(&worker);
+            for thief in thieves {
+                all.extend(thief.join().unwrap());
+            }
+            all
+        });
+        assert!(stealers.iter().all(ConcurrentDeque::empty));
+        all.sort();
+        assert!(all.into_iter().eq(1..total - 1));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn concurrent_list() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let rounds : u64 = if cfg!(miri) { 20 } else { 2000 };
+        let list : Arc<ConcurrentList<u64>> = Arc::new(ConcurrentList::new());
+        for i in 0..100 {
+            list.append(i);
+        }
+
+        let writers : Vec<_> = (0..4).map(|w| {
+            let list = list.clone();
+            thread::spawn(move || {
+                for i in 0..rounds {
+                    let value = 1000 + w * rounds + i;
+                    let node = if i % 2 == 0 { list.push_back(value) } else { list.push_front(value) };
+                    let before = list.insert_before(&node, value).unwrap();
+                    assert!(list.remove_node(&node));
+                    assert!(!list.remove_node(&node));
+                    assert!(list.remove_node(&before));
+                    list.insert_at(value, (w * 25) as usize);
+                }
+            })
+        }).collect();
+        let readers : Vec<_> = (0..2).map(|_| {
+            let list = list.clone();
+            thread::spawn(move || {
+                for _ in 0..(rounds / 10) {
+                    let original : Vec<u64> = list.snapshot().filter(|value| *value < 100).collect();
+                    assert!(original.into_iter().eq(0..100));
+                    let mut count = 0;
+                    list.for_each(|_| count += 1);
+                    assert!(count >= 100);
+                }
+            })
+        }).collect();
+        for thread in writers.into_iter().chain(readers) {
+            thread.join().unwrap();
+        }
+
+        let all : Vec<u64> = list.snapshot().collect();
+        assert_eq!(list.size(), 100 + 4 * rounds as usize);
+        let mut inserted : Vec<u64> = all.iter().cloned().filter(|value| *value >= 1000).collect();
+        inserted.sort();
+        assert!(inserted.into_iter().eq(1000..(1000 + 4 * rounds)));
+
+        let removers : Vec<_> = (0..4).map(|_| {
+            let list = list.clone();
+            thread::spawn(move || {
+                for _ in 0..rounds {
+                    assert_eq!(list.remove_at(0), 0);
+                }
+            })
+        }).collect();
+        for thread in removers {
+            thread.join().unwrap();
+        }
+        assert!(list.snapshot().eq(all[(4 * rounds as usize)..].iter().cloned()));
+
+        let small : ConcurrentList<u64> = ConcurrentList::default();
+        let middle = small.push_back(2);
+        small.push_front(1);
+        small.append(3);
+        assert_eq!(middle.value(), Some(2));
+        assert!(small.remove_node(&middle));
+        assert!(middle.is_removed());
+        assert_eq!(small.value_at(1), Some(3));
+        assert_eq!(small.remove_at(2), 2);
+        assert_eq!(format!("{}", small), "[1,3]");
+        small.clear();
+        assert_eq!(small.remove_at(0), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn shared_list_trait() {
+        use std::thread;
+
+        //Every thread appends its own range of values, so the end result is known.
+        fn fill<L : SharedList<u64> + Sync>(list : &L, threads : u64, per_thread : u64) {
+            thread::scope(|scope| {
+                for t in 0..threads {
+                    scope.spawn(move || {
+                        for i in 0..per_thread {
+                            assert_eq!(list.append(t * per_thread + i), 0);
+                        }
+                    });
+                }
+            });
+        }
+
+        let per_thread : u64 = if cfg!(miri) { 10 } else { 500 };
+        let list : ConcurrentList<u64> = ConcurrentList::new();
+        fill(&list, 4, per_thread);
+        assert_eq!(SharedList::size(&list), 4 * per_thread as usize);
+
+        let mut values : Vec<u64> = list.snapshot().collect();
+        values.sort();
+        assert!(values.into_iter().eq(0..4 * per_thread));
+
+        let shared : &dyn SharedList<u64> = &list;
+        assert_eq!(shared.insert_at(7, 0), 0);
+        assert_eq!(shared.value_at(0), Some(7));
+        assert_eq!(shared.remove_at(0), 0);
+        assert_eq!(shared.value_at(4 * per_thread as usize), None);
+        assert_eq!(shared.remove_at(4 * per_thread as usize), 2);
+    }
+
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
-use std::fmt;
-use std::cell::RefCell;
-use std::rc::Rc;
+use core::fmt;
+use core::cell::RefCell;
+use alloc::rc::Rc;
+use alloc::{string::ToString, vec::Vec};
 
 pub trait List<T> {
     /**
@@ -15,7 +16,8 @@ pub trait List<T> {
     /**
     * #brief insert an element so it ends up in the specified index.
     * 
-    * DoublyLinkedList implements this method in O(n) for time and O(1) for memory.
+    * DoublyLinkedList implements this method in O(n) for time and O(1) for memory, index 0 and the
+    * size are O(1) since they link next to the head.
     * 
     * @param T value element to be inserted.
     * @param usize index The new element will be at this index.
@@ -184,57 +186,197 @@ pub trait Deque<T>  {
     fn pop_front(&mut self) -> T;
 }
 
+pub trait PersistentDeque<T> : Sized {
+    /**
+    * @brief To check if the Deque is empty.
+    * 
+    * @return bool true if it's empty, false otherwise.
+    */
+    fn empty(&self) -> bool;
+
+    /**
+    * @brief Pushes an element to the end.
+    * 
+    * @return Self A new version with the element, self is left untouched.
+    */
+    fn push_back(&self, value : T) -> Self;
+
+    /**
+    * @brief Pops the last element.
+    * 
+    * @return (T, Self) Element that was at the back and a new version without it.
+    */
+    fn pop_back(&self) -> (T, Self);
+
+    /**
+    * @brief Pushes an element to the front.
+    * 
+    * @return Self A new version with the element, self is left untouched.
+    */
+    fn push_front(&self, value : T) -> Self;
+
+    /**
+    * @brief Pops the element in the beggining.
+    * 
+    * @return (T, Self) Element that was at the beggining and a new version without it.
+    */
+    fn pop_front(&self) -> (T, Self);
+}
+
+//Deque shared between threads, any handle can pop from the front at the same time.
+pub trait ConcurrentDeque<T> {
+    /**
+    * @brief To check if the Deque is empty, only a guess while other threads are working.
+    *
+    * @return bool true if it's empty, false otherwise.
+    */
+    fn empty(&self) -> bool;
+
+    /**
+    * @brief Pops the element in the beggining, it can be called from any thread.
+    *
+    * @return Option<T> Element previously located at the beggining, None if it was empty.
+    */
+    fn pop_front(&self) -> Option<T>;
+}
+
+//Handle that owns the back of a ConcurrentDeque, only one thread pushes and pops there.
+pub trait OwnedDeque<T> : ConcurrentDeque<T> {
+    /**
+    * @brief Pushes an element to the end.
+    *
+    * @return &mut Self A reference to itself.
+    */
+    fn push_back(&mut self, value : T) -> &mut Self;
+
+    /**
+    * @brief Pops the last element.
+    *
+    * @return Option<T> Element that was previously at the back, None if there was none left.
+    */
+    fn pop_back(&mut self) -> Option<T>;
+}
+
+//The operations of List for a list shared between threads, they take &self and return copies.
+pub trait SharedList<T> {
+    /**
+    * @brief inserts an element to the end of the list.
+    *
+    * @return u8 0 on success, 1 otherwise.
+    */
+    fn append(&self, value : T) -> u8;
+
+    /**
+    * @brief insert an element so it ends up in the specified index.
+    *
+    * @return u8 0 on success.
+    */
+    fn insert_at(&self, value : T, index : usize) -> u8;
+
+    /**
+    * @brief removes an element at the specified index from the list.
+    *
+    * @return u8 0 on success, 1 if the list is already empy or 2 if index is out of bounds.
+    */
+    fn remove_at(&self, index : usize) -> u8;
+
+    /**
+    * @brief returns a copy of the value stored at the specified index.
+    *
+    * @return Option<T> The element, None if index is out of bounds by the time it's reached.
+    */
+    fn value_at(&self, index : usize) -> Option<T>;
+
+    /**
+    * @brief The number of elements stored in the list, only a guess while other threads are working.
+    *
+    * @return usize number of elements inside the list.
+    */
+    fn size(&self) -> usize;
+}
+
 #[derive(Debug, Clone)]
-pub struct LinkedNode<T : Clone + fmt::Display + std::convert::From<T>> {
+pub struct LinkedNode<T : Clone> {
     next : Option<Rc<LinkedNode<T>>>,
-    value : T,
+    //None in the centinel node of new lists, so an empty list needs no value: map or zip over an
+    //empty list has no element of the new type to put there. The value of the node a list points
+    //to is never read, so an element node is also the list of the elements after it, which lets
+    //the tails share nodes without allocating.
+    value : Option<T>,
+}
+
+//Borrowed view of a LinkedNode list meant to be used with match.
+pub enum ListView<'a, T : Clone> {
+    Nil,
+    Cons(&'a T, Rc<LinkedNode<T>>),
+}
+
+pub struct Iter<'a, T : Clone> {
+    current : Option<&'a LinkedNode<T>>,
 }
 
 #[derive(Debug, Clone)]
-pub struct DoubleNode<T : Clone + fmt::Display + std::convert::From<T>> {
+pub struct DoubleNode<T : Clone + fmt::Display + core::convert::From<T>> {
     next : Option<Rc<RefCell<DoubleNode<T>>>>,
     prev :  Option<Rc<RefCell<DoubleNode<T>>>>,
     value : T,
 }
 
 #[derive(Debug)]
-pub struct DoublyLinkedList<T : Clone + fmt::Display + std::convert::From<T>> {
+pub struct DoublyLinkedList<T : Clone + fmt::Display + core::convert::From<T>> {
     head : Option<Rc<RefCell<DoubleNode<T>>>>,
     n : usize,
 }
 
-///
+pub struct DoublyIter<'a, T : Clone + fmt::Display + core::convert::From<T>> {
+    current : Option<Rc<RefCell<DoubleNode<T>>>>,
+    remaining : usize,
+    list : PhantomData<&'a DoublyLinkedList<T>>,
+}
+
+//Iterator over the elements removed by DoublyLinkedList::drain, they are already unlinked from the list.
+pub struct Drain<T : Clone + fmt::Display + core::convert::From<T>> {
+    current : Option<Rc<RefCell<DoubleNode<T>>>>,
+    remaining : usize,
+}
+
+//
 //////////////////////////////////////////////////
 //Implementation part
 //////////////////////////////////////////////////
-/// 
+//
 
-use std::string::String;
-use std::ops::AddAssign;
-use std::cmp;
+use alloc::string::String;
+use core::ops::AddAssign;
+use core::cmp::Ordering;
+use core::ops::{Bound, RangeBounds};
+use core::marker::PhantomData;
 
-impl<T : Clone + fmt::Display + std::convert::From<T>> LinkedNode<T> {
+impl<T : Clone> LinkedNode<T> {
     //Each list will use a centinel value.
 
     pub fn new(value : T) -> Rc<LinkedNode<T>> { 
         let new = LinkedNode {
-            value : value.clone(), next : None, 
+            value : Some(value), next : None, 
         };
 
-        let rc_new = Rc::from(new);
-
         Rc::from(LinkedNode {
-            value, next : Option::from(rc_new), 
+            value : None, next : Option::from(Rc::from(new)), 
         })
     }  
 
-    //This creates an empty list. value parameter is needed because we don't know what type of value T will be.
-    pub fn empty_list(value : T) -> Rc<LinkedNode<T>> {
+    //Creates an empty list.
+    pub fn nil() -> Rc<LinkedNode<T>> {
         Rc::from(LinkedNode {
-            value, next : None,
+            value : None, next : None,
         })
     }
 
+    //This creates an empty list, value only tells the type of the elements and isn't stored.
+    pub fn empty_list(_value : T) -> Rc<LinkedNode<T>> {
+        LinkedNode::nil()
+    }
+
     pub fn has_next(&self) -> bool {
         if self.is_empty() {
             return false;
@@ -247,7 +389,7 @@ impl<T : Clone + fmt::Display + std::convert::From<T>> LinkedNode<T> {
             panic!("There is no next on empty list.");
         }
         let new_list = LinkedNode {
-            value : self.value.clone(), next : self.next.as_ref().unwrap().next.clone() 
+            value : None, next : self.next.as_ref().unwrap().next.clone() 
         };
 
         Rc::from(new_list)
@@ -257,20 +399,15 @@ impl<T : Clone + fmt::Display + std::convert::From<T>> LinkedNode<T> {
         if element.is_empty() {
             return list;
         }
-        if list.is_empty() {
-            return LinkedNode::new(element.value().clone());
-        }
 
-        let new  = LinkedNode {
-            value : element.value().clone(),
+        let new = LinkedNode {
+            value : Some(element.value().clone()),
             next : list.next.clone(),
         };
 
-        let new_rc = Rc::from(new);
-
         Rc::from(LinkedNode {
-            value : element.value.clone(),
-            next : Option::from(new_rc), 
+            value : None,
+            next : Option::from(Rc::from(new)), 
         })
     }
 
@@ -282,7 +419,79 @@ impl<T : Clone + fmt::Display + std::convert::From<T>> LinkedNode<T> {
         if self.is_empty() {
             panic!("There is no value inside empty list.");
         }
-        return &self.next.as_ref().unwrap().value; 
+        self.next.as_ref().unwrap().value.as_ref().unwrap()
+    }
+
+    /**
+    * @brief Splits the list into its first element and the rest of the list.
+    * 
+    * This method is implemented in O(1) for time and memory and doesn't allocate, the tail is
+    * the node of the first element, which as a list holds the elements after it.
+    * 
+    * @return Option<(&T, Rc<LinkedNode<T>>)> None if the list is empty.
+    */
+    pub fn uncons(&self) -> Option<(&T, Rc<LinkedNode<T>>)> {
+        let first = self.next.as_ref()?;
+        Some((first.value.as_ref().unwrap(), first.clone()))
+    }
+
+    /**
+    * @brief The first element of the list.
+    * 
+    * @return Option<&T> None if the list is empty.
+    */
+    pub fn head(&self) -> Option<&T> {
+        self.next.as_ref()?.value.as_ref()
+    }
+
+    /**
+    * @brief The list without its first element.
+    * 
+    * @return Option<Rc<LinkedNode<T>>> None if the list is empty.
+    */
+    pub fn tail(&self) -> Option<Rc<LinkedNode<T>>> {
+        self.uncons().map(|(_, tail)| tail)
+    }
+
+    /**
+    * @brief Same as uncons but as an enum, so both cases can be matched directly.
+    * 
+    * @return ListView<T> Nil if the list is empty, Cons(head, tail) otherwise.
+    */
+    pub fn view(&self) -> ListView<'_, T> {
+        match self.uncons() {
+            Some((head, tail)) => ListView::Cons(head, tail),
+            None => ListView::Nil,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { current : self.next.as_deref() }
+    }
+
+    //Builds a list with values in front of list, sharing all the nodes of list.
+    pub(crate) fn prepend_all(values : Vec<T>, list : &Rc<LinkedNode<T>>) -> Rc<LinkedNode<T>> {
+        if values.is_empty() {
+            return list.clone();
+        }
+
+        let mut first = list.next.clone();
+        for value in values.into_iter().rev() {
+            first = Some(Rc::from(LinkedNode { value : Some(value), next : first }));
+        }
+
+        Rc::from(LinkedNode { value : None, next : first })
+    }
+
+    //Copies every segment but the last one, which is shared.
+    fn concat_segments(mut segments : Vec<Rc<LinkedNode<T>>>) -> Rc<LinkedNode<T>> {
+        let last = match segments.pop() {
+            Some(last) => last,
+            None => return LinkedNode::nil(),
+        };
+
+        let values = segments.iter().flat_map(|segment| segment.iter().cloned()).collect();
+        LinkedNode::prepend_all(values, &last)
     }
 
     fn reverse_help(queued : Rc<LinkedNode<T>>, reversed : Rc<LinkedNode<T>>) -> Rc<LinkedNode<T>> {
@@ -290,17 +499,40 @@ impl<T : Clone + fmt::Display + std::convert::From<T>> LinkedNode<T> {
             return reversed;
         }
         if !queued.has_next() {
-            return InmutList::insert_at(reversed, LinkedNode::new(queued.value().clone()), 0);
+            InmutList::insert_at(reversed, LinkedNode::new(queued.value().clone()), 0)
         }
         else {
-            return LinkedNode::reverse_help(queued.next(), 
+            LinkedNode::reverse_help(queued.next(), 
                 InmutList::insert_at(reversed, LinkedNode::new(queued.value().clone()), 0))
         }
     }
 }
 
+impl<'a, T : Clone> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.current?;
+        self.current = node.next.as_deref();
+        node.value.as_ref()
+    }
+}
+
+//Long lists would overflow the stack if the nodes were dropped recursively.
+impl<T : Clone> Drop for LinkedNode<T> {
+    fn drop(&mut self) {
+        let mut next = self.next.take();
+        while let Some(node) = next {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => next = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
 impl<T>  fmt::Display for LinkedNode<T> 
-    where T : fmt::Display + Clone + std::convert::From<T>
+    where T : fmt::Display + Clone
 { 
     fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
         let mut result = String::from("[");
@@ -325,8 +557,8 @@ impl<T>  fmt::Display for LinkedNode<T>
     }
 }
 
-impl<'a, T> InmutList<T> for Rc<LinkedNode<T>> 
-    where T : Clone + fmt::Display + std::convert::From<T>
+impl<T> InmutList<T> for Rc<LinkedNode<T>> 
+    where T : Clone
 {
 
     fn append(list : Rc<LinkedNode<T>>, other_list : Rc<LinkedNode<T>>) -> Rc<LinkedNode<T>> {
@@ -359,7 +591,7 @@ impl<'a, T> InmutList<T> for Rc<LinkedNode<T>>
         }
         if index == 0 {
             if count == 1 && !list.has_next() {
-                return LinkedNode::empty_list(list.value.clone());
+                return LinkedNode::nil();
             } 
             if list.is_empty() {
                 panic!("Invalid count or index at remove_at function.");
@@ -406,12 +638,274 @@ impl<'a, T> InmutList<T> for Rc<LinkedNode<T>>
 
         if size < 2 {return self.clone();}
 
-        LinkedNode::reverse_help(self.clone(), LinkedNode::empty_list(self.value.clone()))
+        LinkedNode::reverse_help(self.clone(), LinkedNode::nil())
+    }
+
+}
+
+//Functional combinators. They never modify the original list and share its nodes when possible.
+impl<T : Clone> LinkedNode<T> {
+    /**
+    * @brief Applies f to every element.
+    * 
+    * @return Rc<LinkedNode<U>> A new list with the results in the same order.
+    */
+    pub fn map<U : Clone, F : FnMut(&T) -> U>(&self, f : F) -> Rc<LinkedNode<U>> {
+        LinkedNode::prepend_all(self.iter().map(f).collect(), &LinkedNode::nil())
+    }
+
+    /**
+    * @brief Keeps the elements for which predicate returns true.
+    * 
+    * The nodes after the last removed element are shared with the original list.
+    * 
+    * @return Rc<LinkedNode<T>> A new list with the kept elements.
+    */
+    pub fn filter<P : FnMut(&T) -> bool>(&self, mut predicate : P) -> Rc<LinkedNode<T>> {
+        let mut kept : Vec<T> = Vec::new();
+        let mut run : Vec<&T> = Vec::new();
+        let mut suffix = &self.next;
+        let mut current = &self.next;
+
+        while let Some(node) = current {
+            let value = node.value.as_ref().unwrap();
+            if predicate(value) {
+                run.push(value);
+            } else {
+                kept.extend(run.drain(..).cloned());
+                suffix = &node.next;
+            }
+            current = &node.next;
+        }
+
+        LinkedNode::prepend_all(kept, &Rc::from(LinkedNode { value : None, next : suffix.clone() }))
+    }
+
+    /**
+    * @brief Applies f to every element and keeps the Some results.
+    * 
+    * @return Rc<LinkedNode<U>> A new list with the unwrapped results.
+    */
+    pub fn filter_map<U : Clone, F : FnMut(&T) -> Option<U>>(&self, f : F) -> Rc<LinkedNode<U>> {
+        LinkedNode::prepend_all(self.iter().filter_map(f).collect(), &LinkedNode::nil())
+    }
+
+    /**
+    * @brief Applies f to every element and concatenates the resulting lists.
+    * 
+    * The list returned for the last element is shared, the others are copied.
+    * 
+    * @return Rc<LinkedNode<U>> A new list with all the results.
+    */
+    pub fn flat_map<U : Clone, F : FnMut(&T) -> Rc<LinkedNode<U>>>(&self, f : F) -> Rc<LinkedNode<U>> {
+        LinkedNode::concat_segments(self.iter().map(f).collect())
+    }
+
+    /**
+    * @brief Pairs the elements of both lists by position.
+    * 
+    * @param other The list whose elements become the second component of each pair.
+    * 
+    * @return Rc<LinkedNode<(T, U)>> A list as long as the shortest of both lists.
+    */
+    pub fn zip<U : Clone>(&self, other : &LinkedNode<U>) -> Rc<LinkedNode<(T, U)>> {
+        let pairs = self.iter().cloned().zip(other.iter().cloned()).collect();
+        LinkedNode::prepend_all(pairs, &LinkedNode::nil())
+    }
+
+    /**
+    * @brief The first n elements of the list.
+    * 
+    * If the list has n elements or less it is returned as it is.
+    * 
+    * @return Rc<LinkedNode<T>> A new list with at most n elements.
+    */
+    pub fn take(&self, n : usize) -> Rc<LinkedNode<T>> {
+        if self.iter().nth(n).is_none() {
+            return Rc::from(self.clone());
+        }
+        LinkedNode::prepend_all(self.iter().take(n).cloned().collect(), &LinkedNode::nil())
+    }
+
+    /**
+    * @brief The list without its first n elements.
+    * 
+    * Known as drop(n) in most functional languages, named like Iterator::skip and the skip of the
+    * other persistent lists since list.drop(n) would resolve to Drop::drop of the Rc.
+    * This method is implemented in O(n) for time and O(1) for memory, the remaining nodes are shared
+    * and it only allocates when n is 0 or the list is empty.
+    * 
+    * @return Rc<LinkedNode<T>> The suffix of the list, empty if n is bigger than the list.
+    */
+    pub fn skip(&self, n : usize) -> Rc<LinkedNode<T>> {
+        //The node of the last dropped element is the list of the elements after it.
+        let mut last = None;
+        let mut current = &self.next;
+        for _ in 0..n {
+            match current {
+                Some(node) => { last = Some(node); current = &node.next; }
+                None => break,
+            }
+        }
+
+        match last {
+            Some(node) => node.clone(),
+            None => Rc::from(LinkedNode { value : None, next : self.next.clone() }),
+        }
+    }
+
+    /**
+    * @brief The longest prefix whose elements satisfy predicate.
+    * 
+    * @return Rc<LinkedNode<T>> A new list, or the same list if every element satisfies predicate.
+    */
+    pub fn take_while<P : FnMut(&T) -> bool>(&self, mut predicate : P) -> Rc<LinkedNode<T>> {
+        let mut values : Vec<T> = Vec::new();
+        for value in self.iter() {
+            if !predicate(value) {
+                return LinkedNode::prepend_all(values, &LinkedNode::nil());
+            }
+            values.push(value.clone());
+        }
+
+        Rc::from(self.clone())
+    }
+
+    /**
+    * @brief Combines the elements from the first to the last one.
+    * 
+    * @return B f(...f(f(init, x0), x1)..., xn).
+    */
+    pub fn fold_left<B, F : FnMut(B, &T) -> B>(&self, init : B, f : F) -> B {
+        self.iter().fold(init, f)
+    }
+
+    /**
+    * @brief Combines the elements from the last to the first one.
+    * 
+    * @return B f(x0, f(x1, ...f(xn, init)...)).
+    */
+    pub fn fold_right<B, F : FnMut(&T, B) -> B>(&self, init : B, mut f : F) -> B {
+        let values : Vec<&T> = self.iter().collect();
+        values.into_iter().rev().fold(init, |acc, value| f(value, acc))
+    }
+
+    /**
+    * @brief Joins a list of lists into a single list.
+    * 
+    * The last list is shared, the others are copied.
+    * 
+    * @return Rc<LinkedNode<T>> A list with the elements of every list in order.
+    */
+    pub fn concat(lists : &LinkedNode<Rc<LinkedNode<T>>>) -> Rc<LinkedNode<T>> {
+        LinkedNode::concat_segments(lists.iter().cloned().collect())
+    }
+
+    /**
+    * @brief Inserts every element of values so the first one ends up at the specified index.
+    * 
+    * This method is implemented in O(index + k) for time and memory, the nodes after index are shared.
+    * 
+    * @return Rc<LinkedNode<T>> A new list with the inserted elements.
+    */
+    pub fn insert_all_at<I : IntoIterator<Item = T>>(&self, index : usize, values : I) -> Rc<LinkedNode<T>> {
+        let mut prefix : Vec<T> = self.iter().take(index).cloned().collect();
+        if prefix.len() < index {
+            panic!("Invalid index at function insert_all_at.");
+        }
+
+        prefix.extend(values);
+        LinkedNode::prepend_all(prefix, &self.skip(index))
+    }
+
+    /**
+    * @brief Places separator between every pair of adjacent elements.
+    * 
+    * The list [0,1,2] will become [0,s,1,s,2].
+    * 
+    * @return Rc<LinkedNode<T>> A new list with the separators.
+    */
+    pub fn intersperse(&self, separator : T) -> Rc<LinkedNode<T>> {
+        let mut values : Vec<T> = Vec::new();
+        for value in self.iter() {
+            if !values.is_empty() {
+                values.push(separator.clone());
+            }
+            values.push(value.clone());
+        }
+
+        LinkedNode::prepend_all(values, &LinkedNode::nil())
+    }
+}
+
+//Search. LinkedNode can only be walked forward, so rposition needs a full pass.
+impl<T : Clone> LinkedNode<T> {
+    pub fn contains(&self, value : &T) -> bool
+        where T : PartialEq
+    {
+        self.iter().any(|element| element == value)
+    }
+
+    //Index of the first element equal to value.
+    pub fn index_of(&self, value : &T) -> Option<usize>
+        where T : PartialEq
+    {
+        self.position(|element| element == value)
     }
 
+    //Index of the first element that satisfies predicate.
+    pub fn position<P : FnMut(&T) -> bool>(&self, predicate : P) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    //Index of the last element that satisfies predicate.
+    pub fn rposition<P : FnMut(&T) -> bool>(&self, mut predicate : P) -> Option<usize> {
+        self.iter().enumerate().filter(|(_, element)| predicate(element)).last().map(|(i, _)| i)
+    }
+
+    //First element that satisfies predicate.
+    pub fn find<P : FnMut(&T) -> bool>(&self, mut predicate : P) -> Option<&T> {
+        self.iter().find(|element| predicate(element))
+    }
+
+    /**
+    * @brief Binary search over a list sorted according to f.
+    * 
+    * f is called O(log n) times, walking the list is still O(n).
+    * 
+    * @param F f returns how an element compares to the one being searched.
+    * 
+    * @return Result<usize, usize> Ok with the index of a matching element, or Err with the index
+    *  where it could be inserted keeping the order.
+    */
+    pub fn binary_search_by<F : FnMut(&T) -> Ordering>(&self, mut f : F) -> Result<usize, usize> {
+        let mut low : usize = 0;
+        let mut high = self.iter().count();
+        //Node at index low, the walk to each middle starts here.
+        let mut low_node = self.next.as_deref();
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let mut mid_node = low_node.unwrap();
+            for _ in low..mid {
+                mid_node = mid_node.next.as_deref().unwrap();
+            }
+
+            match f(mid_node.value.as_ref().unwrap()) {
+                Ordering::Equal => return Ok(mid),
+                Ordering::Less => {
+                    low = mid + 1;
+                    low_node = mid_node.next.as_deref();
+                }
+                Ordering::Greater => high = mid,
+            }
+        }
+
+        Err(low)
+    }
 }
 
-impl<T : Clone + fmt::Display + std::convert::From<T>> DoubleNode<T> {
+impl<T : Clone + fmt::Display + core::convert::From<T>> DoubleNode<T> {
 
     fn new(value : T) -> DoubleNode<T> { 
         DoubleNode {
@@ -428,7 +922,7 @@ impl<T : Clone + fmt::Display + std::convert::From<T>> DoubleNode<T> {
     }
 }
 
-impl<T : Clone + fmt::Display + std::convert::From<T>> DoublyLinkedList<T> {
+impl<T : Clone + fmt::Display + core::convert::From<T>> DoublyLinkedList<T> {
     pub fn new() -> DoublyLinkedList<T> {
         DoublyLinkedList {
             head : None, n : 0,
@@ -490,11 +984,449 @@ impl<T : Clone + fmt::Display + std::convert::From<T>> DoublyLinkedList<T> {
         self.n -= 1;
         node.borrow().clone()
     }
+
+    /**
+    * @brief Inserts every element of values so the first one ends up at the specified index.
+    * 
+    * The position is found once and the new nodes are linked one after the other, so this method
+    * is implemented in O(min(index, n - index) + k) for time, k being the number of inserted elements.
+    * 
+    * @param usize index The first new element will be at this index.
+    * @param I values Elements to be inserted, in order.
+    * 
+    * @return usize number of inserted elements.
+    */
+    pub fn insert_all_at<I : IntoIterator<Item = T>>(&mut self, index : usize, values : I) -> usize {
+        if index > self.n {
+            panic!("Index out of bounds in function insert_all_at.");
+        }
+
+        let mut first : Option<Rc<RefCell<DoubleNode<T>>>> = None;
+        let mut last : Option<Rc<RefCell<DoubleNode<T>>>> = None;
+        let mut count : usize = 0;
+
+        for value in values {
+            let node = Rc::from(RefCell::from(DoubleNode::new(value)));
+            match &last {
+                Some(prev) => {
+                    prev.borrow_mut().next = Option::from(node.clone());
+                    node.borrow_mut().prev = Option::from(prev.clone());
+                }
+                None => first = Option::from(node.clone()),
+            }
+            last = Option::from(node);
+            count += 1;
+        }
+
+        let (first, last) = match (first, last) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return 0,
+        };
+
+        //An empty list becomes a ring made only of the new nodes.
+        let (prev_node, next_node) = if self.n == 0 {
+            (last.clone(), first.clone())
+        } else {
+            let next_node = if index == self.n { self.at(0) } else { self.at(index) };
+            let prev_node = next_node.borrow().prev().unwrap();
+            (prev_node, next_node)
+        };
+
+        prev_node.borrow_mut().next = Option::from(first.clone());
+        first.borrow_mut().prev = Option::from(prev_node);
+        last.borrow_mut().next = Option::from(next_node.clone());
+        next_node.borrow_mut().prev = Option::from(last);
+
+        if index == 0 {self.head = Option::from(first);}
+        self.n += count;
+
+        count
+    }
+
+    /**
+    * @brief Removes the elements in range and returns them through an iterator.
+    * 
+    * The nodes are unlinked right away, the iterator hands out their values and frees them.
+    * Elements not consumed are dropped together with the iterator.
+    * This method is implemented in O(start + len) for time and O(1) for memory.
+    * 
+    * @param R range Indexes of the elements to be removed. Panics if it is out of bounds.
+    * 
+    * @return Drain<T> iterator that yields the removed elements in order.
+    */
+    pub fn drain<R : RangeBounds<usize>>(&mut self, range : R) -> Drain<T> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.n,
+        };
+        if start > end || end > self.n {
+            panic!("Invalid range in function drain.");
+        }
+
+        let len = end - start;
+        if len == 0 {
+            return Drain { current : None, remaining : 0 };
+        }
+
+        let first = self.at(start);
+        let mut last = first.clone();
+        for _ in 1..len {
+            last = last.clone().borrow().next().unwrap();
+        }
+
+        if len == self.n {
+            self.head = None;
+        } else {
+            let prev_node = first.borrow().prev().unwrap();
+            let next_node = last.borrow().next().unwrap();
+            prev_node.borrow_mut().next = Option::from(next_node.clone());
+            next_node.borrow_mut().prev = Option::from(prev_node);
+            if start == 0 {self.head = Option::from(next_node);}
+        }
+
+        first.borrow_mut().prev = None;
+        last.borrow_mut().next = None;
+        self.n -= len;
+
+        Drain { current : Option::from(first), remaining : len }
+    }
+
+    /**
+    * @brief Removes the elements in range.
+    * 
+    * This method is implemented in O(start + len) for time and O(1) for memory.
+    * 
+    * @return usize number of removed elements.
+    */
+    pub fn remove_range<R : RangeBounds<usize>>(&mut self, range : R) -> usize {
+        self.drain(range).count()
+    }
+
+    /**
+    * @brief Keeps the first len elements and removes the rest.
+    * 
+    * Nothing happens if the list has len elements or less.
+    */
+    pub fn truncate(&mut self, len : usize) {
+        if len < self.n {
+            self.remove_range(len..);
+        }
+    }
+
+    //Removes every element.
+    pub fn clear(&mut self) {
+        self.remove_range(..);
+    }
     
 }
 
-impl<'a, T> List<T> for DoublyLinkedList<T> 
-    where T : Clone + fmt::Display + std::convert::From<T>
+//Search.
+impl<T : Clone + fmt::Display + core::convert::From<T>> DoublyLinkedList<T> {
+    pub fn iter(&self) -> DoublyIter<'_, T> {
+        DoublyIter { current : self.head.clone(), remaining : self.n, list : PhantomData }
+    }
+
+    pub fn contains(&self, value : &T) -> bool
+        where T : PartialEq
+    {
+        self.index_of(value).is_some()
+    }
+
+    //Index of the first element equal to value.
+    pub fn index_of(&self, value : &T) -> Option<usize>
+        where T : PartialEq
+    {
+        self.position(|element| element == value)
+    }
+
+    /**
+    * @brief Index of the first element that satisfies predicate.
+    * 
+    * This method is implemented in O(n) for time and O(1) for memory.
+    */
+    pub fn position<P : FnMut(&T) -> bool>(&self, mut predicate : P) -> Option<usize> {
+        let mut current = self.head.clone()?;
+        for i in 0..(self.n) {
+            if predicate(&current.borrow().value) { return Some(i); }
+            let next = current.borrow().next().unwrap();
+            current = next;
+        }
+        None
+    }
+
+    /**
+    * @brief Index of the last element that satisfies predicate.
+    * 
+    * It starts at the last element and follows the prev links, so it stops at the first match from the end.
+    */
+    pub fn rposition<P : FnMut(&T) -> bool>(&self, mut predicate : P) -> Option<usize> {
+        let mut current = self.head.as_ref()?.borrow().prev().unwrap();
+        for i in (0..(self.n)).rev() {
+            if predicate(&current.borrow().value) { return Some(i); }
+            let prev = current.borrow().prev().unwrap();
+            current = prev;
+        }
+        None
+    }
+
+    //First element that satisfies predicate.
+    pub fn find<P : FnMut(&T) -> bool>(&self, mut predicate : P) -> Option<&T> {
+        let mut current = self.head.clone()?;
+        for _ in 0..(self.n) {
+            if predicate(&current.borrow().value) {
+                return Some(unsafe {&(*current.as_ptr()).value});
+            }
+            let next = current.borrow().next().unwrap();
+            current = next;
+        }
+        None
+    }
+
+    //First element that satisfies predicate, as a mutable borrow.
+    pub fn find_mut<P : FnMut(&T) -> bool>(&mut self, mut predicate : P) -> Option<&mut T> {
+        let mut current = self.head.clone()?;
+        for _ in 0..(self.n) {
+            if predicate(&current.borrow().value) {
+                return Some(unsafe {&mut (*current.as_ptr()).value});
+            }
+            let next = current.borrow().next().unwrap();
+            current = next;
+        }
+        None
+    }
+
+    /**
+    * @brief Binary search over a list sorted according to f.
+    * 
+    * f is called O(log n) times. The walk always starts from the last visited node, so the
+    * traversal adds up to O(n) instead of O(n log n).
+    * 
+    * @param F f returns how an element compares to the one being searched.
+    * 
+    * @return Result<usize, usize> Ok with the index of a matching element, or Err with the index
+    *  where it could be inserted keeping the order.
+    */
+    pub fn binary_search_by<F : FnMut(&T) -> Ordering>(&self, mut f : F) -> Result<usize, usize> {
+        let mut current = match self.head.clone() {
+            Some(head) => head,
+            None => return Err(0),
+        };
+        let mut current_index : usize = 0;
+        let mut low : usize = 0;
+        let mut high = self.n;
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+            while current_index < mid {
+                let next = current.borrow().next().unwrap();
+                current = next;
+                current_index += 1;
+            }
+            while current_index > mid {
+                let prev = current.borrow().prev().unwrap();
+                current = prev;
+                current_index -= 1;
+            }
+
+            match f(&current.borrow().value) {
+                Ordering::Equal => return Ok(mid),
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+            }
+        }
+
+        Err(low)
+    }
+}
+
+//Merging of sorted lists.
+impl<T : Clone + fmt::Display + core::convert::From<T>> DoublyLinkedList<T> {
+    /**
+    * @brief Moves every node of other into this list, both lists must be sorted.
+    * 
+    * The nodes of other are relinked, nothing is allocated or cloned. Equal elements of self
+    * come before the ones of other. This method is implemented in O(n + m) for time and O(1) for memory.
+    * 
+    * @param DoublyLinkedList<T> other Sorted list, it is consumed.
+    */
+    pub fn merge(&mut self, mut other : DoublyLinkedList<T>)
+        where T : Ord
+    {
+        let mut incoming = other.head.take();
+        let m = other.n;
+        other.n = 0;
+
+        if self.n == 0 {
+            self.head = incoming;
+            self.n = m;
+            return;
+        }
+
+        let mut cursor = self.at(0);
+        //Number of nodes of self that come after cursor, cursor included.
+        let mut remaining = self.n;
+
+        for _ in 0..m {
+            let node = incoming.unwrap();
+            incoming = node.borrow().next();
+
+            while remaining > 0 && cursor.borrow().value <= node.borrow().value {
+                let next = cursor.borrow().next().unwrap();
+                cursor = next;
+                remaining -= 1;
+            }
+
+            //When every node of self was passed cursor is back at the head, so node goes to the end.
+            let cursor_prev = cursor.borrow().prev().unwrap();
+            cursor_prev.borrow_mut().next = Option::from(node.clone());
+            node.borrow_mut().prev = Option::from(cursor_prev);
+            node.borrow_mut().next = Option::from(cursor.clone());
+            cursor.borrow_mut().prev = Option::from(node.clone());
+
+            if remaining > 0 && self.is_head(&cursor) {
+                self.head = Option::from(node);
+            }
+        }
+
+        self.n += m;
+    }
+}
+
+//Relocation. Nodes are relinked, their values are never cloned or moved.
+impl<T : Clone + fmt::Display + core::convert::From<T>> DoublyLinkedList<T> {
+    //Takes node out of the ring and puts it right before target, head is left as it is.
+    fn relink_before(node : &Rc<RefCell<DoubleNode<T>>>, target : &Rc<RefCell<DoubleNode<T>>>) {
+        let prev_node = node.borrow().prev().unwrap();
+        let next_node = node.borrow().next().unwrap();
+        prev_node.borrow_mut().next = Option::from(next_node.clone());
+        next_node.borrow_mut().prev = Option::from(prev_node);
+
+        let target_prev = target.borrow().prev().unwrap();
+        target_prev.borrow_mut().next = Option::from(node.clone());
+        node.borrow_mut().prev = Option::from(target_prev);
+        node.borrow_mut().next = Option::from(target.clone());
+        target.borrow_mut().prev = Option::from(node.clone());
+    }
+
+    fn is_head(&self, node : &Rc<RefCell<DoubleNode<T>>>) -> bool {
+        match &self.head {
+            Some(head) => Rc::ptr_eq(head, node),
+            None => false,
+        }
+    }
+
+    /**
+    * @brief Moves node so it ends up right before target.
+    * 
+    * If target is the first element, node becomes the first element.
+    * This method is implemented in O(1) for time and memory.
+    * 
+    * @param node Handle of a node of this list, as returned by at().
+    * @param target Handle of another node of this list.
+    */
+    pub fn move_before(&mut self, node : Rc<RefCell<DoubleNode<T>>>, target : Rc<RefCell<DoubleNode<T>>>) {
+        let node_next = node.borrow().next().unwrap();
+        if Rc::ptr_eq(&node, &target) {
+            return;
+        }
+        if Rc::ptr_eq(&node_next, &target) {
+            //node is already linked before target, but if target is the head node is the last element.
+            if self.is_head(&target) {
+                self.head = Option::from(node);
+            }
+            return;
+        }
+
+        let node_was_head = self.is_head(&node);
+        let target_was_head = self.is_head(&target);
+        DoublyLinkedList::relink_before(&node, &target);
+
+        if target_was_head {
+            self.head = Option::from(node);
+        } else if node_was_head {
+            self.head = Option::from(node_next);
+        }
+    }
+
+    /**
+    * @brief Moves node to the beggining of the list.
+    * 
+    * This method is implemented in O(1) for time and memory.
+    */
+    pub fn move_to_front(&mut self, node : Rc<RefCell<DoubleNode<T>>>) {
+        let head = self.at(0);
+        self.move_before(node, head);
+    }
+
+    /**
+    * @brief Moves node to the end of the list.
+    * 
+    * This method is implemented in O(1) for time and memory.
+    */
+    pub fn move_to_back(&mut self, node : Rc<RefCell<DoubleNode<T>>>) {
+        let head = self.at(0);
+        if Rc::ptr_eq(&node, &head) {
+            //In a ring the first element becomes the last one by moving the head forward.
+            self.head = node.borrow().next();
+        } else if !Rc::ptr_eq(&node.borrow().next().unwrap(), &head) {
+            DoublyLinkedList::relink_before(&node, &head);
+        }
+    }
+
+    /**
+    * @brief Exchanges the positions of two nodes.
+    * 
+    * This method is implemented in O(1) for time and memory.
+    */
+    pub fn swap_nodes(&mut self, a : Rc<RefCell<DoubleNode<T>>>, b : Rc<RefCell<DoubleNode<T>>>) {
+        if Rc::ptr_eq(&a, &b) {
+            return;
+        }
+
+        let a_next = a.borrow().next().unwrap();
+        let b_next = b.borrow().next().unwrap();
+        if Rc::ptr_eq(&a_next, &b) {
+            DoublyLinkedList::relink_before(&b, &a);
+        } else if Rc::ptr_eq(&b_next, &a) {
+            DoublyLinkedList::relink_before(&a, &b);
+        } else {
+            DoublyLinkedList::relink_before(&a, &b);
+            DoublyLinkedList::relink_before(&b, &a_next);
+        }
+
+        if self.is_head(&a) {
+            self.head = Option::from(b);
+        } else if self.is_head(&b) {
+            self.head = Option::from(a);
+        }
+    }
+
+    /**
+    * @brief Exchanges the elements at indexes i and j.
+    * 
+    * This method is implemented in O(n) for time, to find the nodes, and O(1) for memory.
+    */
+    pub fn swap(&mut self, i : usize, j : usize) {
+        let a = self.at(i);
+        let b = self.at(j);
+        self.swap_nodes(a, b);
+    }
+}
+
+impl<T : Clone + fmt::Display + core::convert::From<T>> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        DoublyLinkedList::new()
+    }
+}
+
+impl<T> List<T> for DoublyLinkedList<T> 
+    where T : Clone + fmt::Display + core::convert::From<T>
 {
 
     fn append(&mut self, value : T) -> u8 {
@@ -525,27 +1457,28 @@ impl<'a, T> List<T> for DoublyLinkedList<T>
             return 0;
         } else if index >= self.n {
             panic!("Index out of bounds in function insert_at.");
+        } else if index == 0 {
+            //The ring has no ends, a node appended before the head becomes the new head in O(1).
+            self.append(value);
+            let last = self.head.as_ref().unwrap().borrow().prev.clone();
+            self.head = last;
+            return 0;
         }
 
         let new_node = DoubleNode::new(value);
 
         let mut prev_node : Rc<RefCell<DoubleNode<T>>>;
         let mut next_node : Rc<RefCell<DoubleNode<T>>>;
-        let inserting_node : Rc<RefCell<DoubleNode<T>>>;
 
         //Just to initialize values.
         prev_node = self.at(0);
         next_node = self.at(0);
         
-        inserting_node = Rc::from(RefCell::from(new_node));
+        let inserting_node = Rc::from(RefCell::from(new_node));
 
-        let prev_index;
-        let next_index;
-
-        if index == 0 {prev_index = self.n - 1; next_index = 0;}
-        else {prev_index = index - 1; next_index = index; }
-
-        let max_index = cmp::max(prev_index, next_index);
+        let prev_index = index - 1;
+        let next_index = index;
+        let max_index = next_index;
 
         let mut current = &mut self.head;
         for i in 0..(max_index + 1) {
@@ -563,7 +1496,6 @@ impl<'a, T> List<T> for DoublyLinkedList<T>
         inserting_node.borrow_mut().next = Option::from(next_node.clone());
         next_node.borrow_mut().prev = Option::from(inserting_node.clone());
         
-        if index == 0 {self.head = Option::from(inserting_node);}
         self.n += 1;
 
         0
@@ -597,11 +1529,11 @@ impl<'a, T> List<T> for DoublyLinkedList<T>
     }
 
     fn value_at(&self, index: usize) -> &T {
-        return unsafe {&(*self.at_as_ref(index).as_ptr()).value}
+        unsafe {&(*self.at_as_ref(index).as_ptr()).value}
     }
     
     fn mut_value_at(&mut self, index : usize) -> &mut T {
-        return unsafe {&mut(*self.at_as_ref(index).as_ptr()).value}
+        unsafe {&mut(*self.at_as_ref(index).as_ptr()).value}
     }
 
     fn size(&self) -> usize {
@@ -610,14 +1542,13 @@ impl<'a, T> List<T> for DoublyLinkedList<T>
 }
 
 impl<T>  fmt::Display for DoublyLinkedList<T> 
-    where T : fmt::Display + Clone + std::convert::From<T>
+    where T : fmt::Display + Clone + core::convert::From<T>
 { 
     fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
         let mut result = String::from("[");
         if self.size() != 0 {
-            let line_append : &str;
-            if self.value_at(0).to_string().len() > 10 {line_append = "\n";}
-            else {line_append = "";}
+            let line_append = if self.value_at(0).to_string().len() > 10 {"\n"}
+                else {""};
 
             result += line_append;
             result.add_assign(self.value_at(0).to_string().as_str());
@@ -636,7 +1567,7 @@ impl<T>  fmt::Display for DoublyLinkedList<T>
 }
 
 impl<T> Reversible for DoublyLinkedList<T> 
-    where T : Clone + fmt::Display + std::convert::From<T> 
+    where T : Clone + fmt::Display + core::convert::From<T> 
 {
 
     fn reverse(&mut self) -> &mut Self {
@@ -661,7 +1592,7 @@ impl<T> Reversible for DoublyLinkedList<T>
 }
 
 impl<T> Clone for DoublyLinkedList<T>
-    where T : Clone + fmt::Display + std::convert::From<T>
+    where T : Clone + fmt::Display + core::convert::From<T>
 {
     fn clone(&self) -> Self {
         if self.n == 0 {
@@ -683,8 +1614,90 @@ impl<T> Clone for DoublyLinkedList<T>
     }
 }
 
+//The nodes form a ring, they would never be freed if the ring wasn't broken.
+impl<T : Clone + fmt::Display + core::convert::From<T>> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<'a, T> Iterator for DoublyIter<'a, T>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.current.take()?;
+        self.current = node.borrow().next();
+        self.remaining -= 1;
+        Some(unsafe {&(*node.as_ptr()).value})
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DoublyLinkedList<T>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    type Item = &'a T;
+    type IntoIter = DoublyIter<'a, T>;
+
+    fn into_iter(self) -> DoublyIter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T : Clone> IntoIterator for &'a LinkedNode<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T> Iterator for Drain<T>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let node = self.current.take()?;
+        self.remaining -= 1;
+
+        if let Some(next_node) = node.borrow_mut().next.take() {
+            next_node.borrow_mut().prev = None;
+            self.current = Option::from(next_node);
+        }
+
+        //Someone may still hold the node, in that case the value is cloned.
+        match Rc::try_unwrap(node) {
+            Ok(cell) => Some(cell.into_inner().value),
+            Err(node) => Some(node.borrow().value.clone()),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> Drop for Drain<T>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
 impl<T> Deque<T> for DoublyLinkedList<T>
-    where T : Clone + fmt::Display + std::convert::From<T>
+    where T : Clone + fmt::Display + core::convert::From<T>
 {
     fn empty(&self) -> bool {
         self.size() == 0
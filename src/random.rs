@@ -0,0 +1,22 @@
+//Seeds for the randomized structures, the levels of SkipList and the priorities of Rope.
+
+#[cfg(feature = "std")]
+pub(crate) fn seed() -> u64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    RandomState::new().build_hasher().finish() | 1
+}
+
+//Without std there is no entropy to use, so each call takes the next value of a splitmix64 sequence.
+#[cfg(not(feature = "std"))]
+pub(crate) fn seed() -> u64 {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS : AtomicUsize = AtomicUsize::new(1);
+
+    let mut z = (CALLS.fetch_add(1, Ordering::Relaxed) as u64).wrapping_mul(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    (z ^ (z >> 31)) | 1
+}
@@ -0,0 +1,353 @@
+use core::fmt;
+use alloc::rc::Rc;
+use alloc::{string::{String, ToString}, vec::Vec};
+
+use crate::list::*;
+
+//Complete binary tree, the element of a node comes before the elements of both children.
+#[derive(Debug)]
+enum Tree<T> {
+    Leaf(T),
+    Node(T, Rc<Tree<T>>, Rc<Tree<T>>),
+}
+
+//Each entry of the spine is a tree together with its number of elements.
+type Spine<T> = Rc<LinkedNode<(usize, Rc<Tree<T>>)>>;
+
+/**
+* Persistent list based on skew binary numbers (Okasaki).
+*
+* The list is a sequence of complete binary trees whose sizes are increasing, only the first
+* two may be equal. cons and uncons are O(1), value_at and update are O(log n).
+*/
+#[derive(Debug, Clone)]
+pub struct RandomAccessList<T : Clone> {
+    spine : Spine<T>,
+    n : usize,
+}
+
+pub struct Iter<'a, T : Clone> {
+    spine : crate::list::Iter<'a, (usize, Rc<Tree<T>>)>,
+    stack : Vec<&'a Tree<T>>,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+impl<T : Clone> Tree<T> {
+    fn value(&self) -> &T {
+        match self {
+            Tree::Leaf(value) => value,
+            Tree::Node(value, _, _) => value,
+        }
+    }
+
+    fn get(&self, size : usize, index : usize) -> &T {
+        match self {
+            Tree::Leaf(value) => value,
+            Tree::Node(value, left, right) => {
+                let half = size / 2;
+                if index == 0 { value }
+                else if index <= half { left.get(half, index - 1) }
+                else { right.get(half, index - 1 - half) }
+            }
+        }
+    }
+
+    fn update(&self, size : usize, index : usize, new_value : T) -> Rc<Tree<T>> {
+        match self {
+            Tree::Leaf(_) => Rc::from(Tree::Leaf(new_value)),
+            Tree::Node(value, left, right) => {
+                let half = size / 2;
+                if index == 0 {
+                    Rc::from(Tree::Node(new_value, left.clone(), right.clone()))
+                } else if index <= half {
+                    Rc::from(Tree::Node(value.clone(), left.update(half, index - 1, new_value), right.clone()))
+                } else {
+                    Rc::from(Tree::Node(value.clone(), left.clone(), right.update(half, index - 1 - half, new_value)))
+                }
+            }
+        }
+    }
+}
+
+impl<T : Clone> RandomAccessList<T> {
+    //Creates a list with a single element, like LinkedNode::new.
+    pub fn new(value : T) -> RandomAccessList<T> {
+        RandomAccessList::nil().cons(value)
+    }
+
+    pub fn nil() -> RandomAccessList<T> {
+        RandomAccessList {
+            spine : LinkedNode::nil(), n : 0,
+        }
+    }
+
+    pub fn from_list(list : &LinkedNode<T>) -> RandomAccessList<T> {
+        let values : Vec<&T> = list.iter().collect();
+        values.into_iter().rev().fold(RandomAccessList::nil(), |acc, value| acc.cons(value.clone()))
+    }
+
+    pub fn to_list(&self) -> Rc<LinkedNode<T>> {
+        let values : Vec<&T> = self.iter().collect();
+        values.into_iter().rev().fold(LinkedNode::nil(), |acc, value| {
+            LinkedNode::cons(LinkedNode::new(value.clone()), acc)
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /**
+    * @brief Inserts an element at the front of the list.
+    *
+    * This method is implemented in O(1) for time and memory.
+    *
+    * @return Self A new list with value as its first element.
+    */
+    pub fn cons(&self, value : T) -> RandomAccessList<T> {
+        if let Some((&(w1, ref t1), rest)) = self.spine.uncons() {
+            if let Some(&(w2, ref t2)) = rest.head() {
+                if w1 == w2 {
+                    let tree = Rc::from(Tree::Node(value, t1.clone(), t2.clone()));
+                    return RandomAccessList {
+                        spine : LinkedNode::cons(LinkedNode::new((2 * w1 + 1, tree)), rest.skip(1)),
+                        n : self.n + 1,
+                    };
+                }
+            }
+        }
+
+        RandomAccessList {
+            spine : LinkedNode::cons(LinkedNode::new((1, Rc::from(Tree::Leaf(value)))), self.spine.clone()),
+            n : self.n + 1,
+        }
+    }
+
+    /**
+    * @brief Splits the list into its first element and the rest of the list.
+    *
+    * This method is implemented in O(1) for time and memory.
+    *
+    * @return Option<(&T, Self)> None if the list is empty.
+    */
+    pub fn uncons(&self) -> Option<(&T, RandomAccessList<T>)> {
+        let (&(size, ref tree), rest) = self.spine.uncons()?;
+
+        let spine = match tree.as_ref() {
+            Tree::Leaf(_) => rest,
+            Tree::Node(_, left, right) => {
+                let half = size / 2;
+                LinkedNode::cons(LinkedNode::new((half, left.clone())),
+                    LinkedNode::cons(LinkedNode::new((half, right.clone())), rest))
+            }
+        };
+
+        Some((tree.value(), RandomAccessList { spine, n : self.n - 1 }))
+    }
+
+    pub fn head(&self) -> Option<&T> {
+        self.spine.head().map(|(_, tree)| tree.value())
+    }
+
+    pub fn tail(&self) -> Option<RandomAccessList<T>> {
+        self.uncons().map(|(_, tail)| tail)
+    }
+
+    /**
+    * @brief returns the value stored at the specified index.
+    *
+    * This method is implemented in O(log n) for time and O(1) for memory.
+    *
+    * @return Option<&T> None if index is out of bounds.
+    */
+    pub fn get(&self, index : usize) -> Option<&T> {
+        let mut index = index;
+        for (size, tree) in self.spine.iter() {
+            if index < *size {
+                return Some(tree.get(*size, index));
+            }
+            index -= size;
+        }
+        None
+    }
+
+    //Sizes of the trees of the spine, counted from the trees themselves.
+    #[cfg(test)]
+    pub(crate) fn tree_sizes(&self) -> Vec<usize> {
+        fn count<T>(tree : &Tree<T>) -> usize {
+            match tree {
+                Tree::Leaf(_) => 1,
+                Tree::Node(_, left, right) => 1 + count(left) + count(right),
+            }
+        }
+        self.spine.iter().map(|(_, tree)| count(tree)).collect()
+    }
+
+    /**
+    * @brief Replaces the element at the specified index.
+    *
+    * This method is implemented in O(log n) for time and memory.
+    *
+    * @return Self A new list with value at index. Panics if index is out of bounds.
+    */
+    pub fn update(&self, index : usize, value : T) -> RandomAccessList<T> {
+        if index >= self.n {
+            panic!("Invalid index at update function.");
+        }
+
+        let mut index = index;
+        let mut before : Vec<(usize, Rc<Tree<T>>)> = Vec::new();
+        for (size, tree) in self.spine.iter() {
+            if index < *size {
+                let updated = (*size, tree.update(*size, index, value));
+                let rest = self.spine.skip(before.len() + 1);
+                let spine = before.into_iter().rev().fold(LinkedNode::cons(LinkedNode::new(updated), rest),
+                    |acc, entry| LinkedNode::cons(LinkedNode::new(entry), acc));
+                return RandomAccessList { spine, n : self.n };
+            }
+            index -= size;
+            before.push((*size, tree.clone()));
+        }
+
+        unreachable!()
+    }
+
+    /**
+    * @brief The list without its first count elements.
+    *
+    * This method is implemented in O(log n) for time and memory, the remaining trees are shared.
+    *
+    * @return Self The suffix of the list, empty if count is bigger than the list.
+    */
+    pub fn skip(&self, count : usize) -> RandomAccessList<T> {
+        if count >= self.n {
+            return RandomAccessList::nil();
+        }
+
+        let n = self.n - count;
+        let mut count = count;
+        let mut spine = self.spine.clone();
+        while let Some((&(size, _), rest)) = spine.uncons() {
+            if count < size {
+                break;
+            }
+            count -= size;
+            spine = rest;
+        }
+
+        //The first tree of spine has to be split, its pieces are pushed from the right.
+        let (mut size, mut tree) = spine.head().unwrap().clone();
+        spine = spine.skip(1);
+        while count > 0 {
+            let (left, right) = match tree.as_ref() {
+                Tree::Node(_, left, right) => (left.clone(), right.clone()),
+                Tree::Leaf(_) => unreachable!(),
+            };
+            size /= 2;
+            count -= 1;
+            if count < size {
+                spine = LinkedNode::cons(LinkedNode::new((size, right)), spine);
+                tree = left;
+            } else {
+                count -= size;
+                tree = right;
+            }
+        }
+
+        RandomAccessList {
+            spine : LinkedNode::cons(LinkedNode::new((size, tree)), spine), n,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { spine : self.spine.iter(), stack : Vec::new() }
+    }
+
+    //Conses the values in order in front of list.
+    fn prepend<'a, I>(values : I, list : RandomAccessList<T>) -> RandomAccessList<T>
+        where I : DoubleEndedIterator<Item = &'a T>, T : 'a
+    {
+        values.rev().fold(list, |acc, value| acc.cons(value.clone()))
+    }
+}
+
+impl<'a, T : Clone> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.stack.is_empty() {
+            let (_, tree) = self.spine.next()?;
+            self.stack.push(tree);
+        }
+
+        let tree = self.stack.pop()?;
+        if let Tree::Node(_, left, right) = tree {
+            self.stack.push(right);
+            self.stack.push(left);
+        }
+        Some(tree.value())
+    }
+}
+
+impl<T : Clone> Default for RandomAccessList<T> {
+    fn default() -> Self {
+        RandomAccessList::nil()
+    }
+}
+
+impl<T> fmt::Display for RandomAccessList<T>
+    where T : fmt::Display + Clone
+{
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        let values : Vec<String> = self.iter().map(|value| value.to_string()).collect();
+        write!(f, "[{}]", values.join(","))
+    }
+}
+
+impl<T> InmutList<T> for RandomAccessList<T>
+    where T : Clone
+{
+    fn append(list : Self, other_list : Self) -> Self {
+        let values : Vec<&T> = list.iter().collect();
+        RandomAccessList::prepend(values.into_iter(), other_list)
+    }
+
+    fn insert_at(list : Self, insert_list : Self, index : usize) -> Self {
+        if index > list.n {
+            panic!("Invalid index at function insert_at.");
+        }
+
+        let values : Vec<&T> = list.iter().take(index).chain(insert_list.iter()).collect();
+        RandomAccessList::prepend(values.into_iter(), list.skip(index))
+    }
+
+    fn remove_at(list : Self, index : usize, count : usize) -> Self {
+        if count == 0 {
+            return list;
+        }
+        if index + count > list.n {
+            panic!("Invalid count or index at remove_at function.");
+        }
+
+        let values : Vec<&T> = list.iter().take(index).collect();
+        RandomAccessList::prepend(values.into_iter(), list.skip(index + count))
+    }
+
+    fn value_at(list : Self, index : usize) -> T {
+        match list.get(index) {
+            Some(value) => value.clone(),
+            None => panic!("Invalid index at value_at function."),
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.n
+    }
+
+    fn reverse(&self) -> Self {
+        self.iter().fold(RandomAccessList::nil(), |acc, value| acc.cons(value.clone()))
+    }
+}
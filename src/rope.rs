@@ -0,0 +1,297 @@
+use core::fmt;
+use alloc::{boxed::Box, string::{String, ToString}, vec::Vec};
+
+use crate::list::*;
+use crate::random;
+
+type Link<T> = Option<Box<RopeNode<T>>>;
+
+#[derive(Debug, Clone)]
+struct RopeNode<T> {
+    value : T,
+    //Heap order on priority keeps the tree balanced in expectation.
+    priority : u64,
+    //Number of elements in this subtree.
+    size : usize,
+    //The children of this subtree still have to be swapped.
+    reversed : bool,
+    left : Link<T>,
+    right : Link<T>,
+}
+
+/**
+* Sequence stored in a treap ordered by position, every node knows the size of its subtree.
+*
+* Indexed access, insert_at, remove_at, split_off and concat are O(log n) expected. reverse is
+* O(1), it only marks the root and the mark is pushed down the next time a path is edited.
+*/
+#[derive(Debug, Clone)]
+pub struct Rope<T : Clone + fmt::Display + core::convert::From<T>> {
+    root : Link<T>,
+    seed : u64,
+}
+
+pub struct RopeIter<'a, T> {
+    //Nodes still to be yielded, with whether their subtree is seen reversed.
+    stack : Vec<(&'a RopeNode<T>, bool)>,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+fn size<T>(node : &Link<T>) -> usize {
+    node.as_ref().map_or(0, |node| node.size)
+}
+
+impl<T> RopeNode<T> {
+    fn update(&mut self) {
+        self.size = 1 + size(&self.left) + size(&self.right);
+    }
+
+    //Applies a pending reverse to the children.
+    fn push(&mut self) {
+        if self.reversed {
+            core::mem::swap(&mut self.left, &mut self.right);
+            if let Some(left) = self.left.as_mut() { left.reversed = !left.reversed; }
+            if let Some(right) = self.right.as_mut() { right.reversed = !right.reversed; }
+            self.reversed = false;
+        }
+    }
+}
+
+//Splits node into its first k elements and the rest.
+fn split<T>(node : Link<T>, k : usize) -> (Link<T>, Link<T>) {
+    let mut node = match node {
+        Some(node) => node,
+        None => return (None, None),
+    };
+    node.push();
+
+    if size(&node.left) >= k {
+        let (left, right) = split(node.left.take(), k);
+        node.left = right;
+        node.update();
+        (left, Some(node))
+    } else {
+        let (left, right) = split(node.right.take(), k - size(&node.left) - 1);
+        node.right = left;
+        node.update();
+        (Some(node), right)
+    }
+}
+
+//Joins two trees, every element of left goes before the ones of right.
+fn merge<T>(left : Link<T>, right : Link<T>) -> Link<T> {
+    match (left, right) {
+        (None, right) => right,
+        (left, None) => left,
+        (Some(mut left), Some(mut right)) => {
+            if left.priority > right.priority {
+                left.push();
+                left.right = merge(left.right.take(), Some(right));
+                left.update();
+                Some(left)
+            } else {
+                right.push();
+                right.left = merge(Some(left), right.left.take());
+                right.update();
+                Some(right)
+            }
+        }
+    }
+}
+
+impl<T : Clone + fmt::Display + core::convert::From<T>> Rope<T> {
+    pub fn new() -> Rope<T> {
+        Rope {
+            root : None, seed : random::seed(),
+        }
+    }
+
+    pub fn iter(&self) -> RopeIter<'_, T> {
+        let mut iter = RopeIter { stack : Vec::new() };
+        iter.descend(&self.root, false);
+        iter
+    }
+
+    //xorshift64.
+    fn next_priority(&mut self) -> u64 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        self.seed
+    }
+
+    /**
+    * @brief Splits the rope in two, self keeps the first at elements.
+    *
+    * This method is implemented in O(log n) expected time.
+    *
+    * @return Rope<T> The elements from index at onwards.
+    */
+    pub fn split_off(&mut self, at : usize) -> Rope<T> {
+        if at > self.size() {
+            panic!("Index out of bounds in function split_off.");
+        }
+
+        let (left, right) = split(self.root.take(), at);
+        self.root = left;
+        Rope { root : right, seed : self.next_priority() }
+    }
+
+    /**
+    * @brief Moves every element of other to the end of self.
+    *
+    * This method is implemented in O(log n) expected time.
+    *
+    * @return &mut Self A reference to itself.
+    */
+    pub fn concat(&mut self, mut other : Rope<T>) -> &mut Self {
+        self.root = merge(self.root.take(), other.root.take());
+        self
+    }
+}
+
+impl<T : Clone + fmt::Display + core::convert::From<T>> Default for Rope<T> {
+    fn default() -> Self {
+        Rope::new()
+    }
+}
+
+impl<T> List<T> for Rope<T>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    fn append(&mut self, value : T) -> u8 {
+        self.insert_at(value, self.size())
+    }
+
+    //O(log n) expected.
+    fn insert_at(&mut self, value : T, index : usize) -> u8 {
+        if index > self.size() {
+            panic!("Index out of bounds in function insert_at.");
+        }
+
+        let node = Box::new(RopeNode {
+            value, priority : self.next_priority(), size : 1, reversed : false, left : None, right : None,
+        });
+        let (left, right) = split(self.root.take(), index);
+        self.root = merge(merge(left, Some(node)), right);
+        0
+    }
+
+    //O(log n) expected.
+    fn remove_at(&mut self, index : usize) -> u8 {
+        if self.size() == 0 { return 1; }
+        if index >= self.size() { return 2; }
+
+        let (left, right) = split(self.root.take(), index);
+        let (_, right) = split(right, 1);
+        self.root = merge(left, right);
+        0
+    }
+
+    //O(log n) expected, pending reverses are taken into account without being pushed.
+    fn value_at(&self, index : usize) -> &T {
+        if index >= self.size() {panic!("Invalid index at value_at function.")};
+
+        let mut node = self.root.as_ref().unwrap();
+        let mut index = index;
+        let mut reversed = false;
+        loop {
+            reversed ^= node.reversed;
+            let (first, second) = if reversed { (&node.right, &node.left) } else { (&node.left, &node.right) };
+
+            let first_size = size(first);
+            if index == first_size {
+                return &node.value;
+            }
+            if index < first_size {
+                node = first.as_ref().unwrap();
+            } else {
+                index -= first_size + 1;
+                node = second.as_ref().unwrap();
+            }
+        }
+    }
+
+    //O(log n) expected.
+    fn mut_value_at(&mut self, index : usize) -> &mut T {
+        if index >= self.size() {panic!("Invalid index at mut_value_at function.")};
+
+        let mut node = self.root.as_mut().unwrap();
+        let mut index = index;
+        loop {
+            node.push();
+            let left_size = size(&node.left);
+            if index == left_size {
+                return &mut node.value;
+            }
+            if index < left_size {
+                node = node.left.as_mut().unwrap();
+            } else {
+                index -= left_size + 1;
+                node = node.right.as_mut().unwrap();
+            }
+        }
+    }
+
+    fn size(&self) -> usize {
+        size(&self.root)
+    }
+}
+
+impl<T> Reversible for Rope<T>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    //O(1), the root is only marked.
+    fn reverse(&mut self) -> &mut Self {
+        if let Some(root) = self.root.as_mut() {
+            root.reversed = !root.reversed;
+        }
+        self
+    }
+}
+
+impl<'a, T> RopeIter<'a, T> {
+    //Stacks node and the nodes that come first in its subtree.
+    fn descend(&mut self, node : &'a Link<T>, reversed : bool) {
+        let mut node = node;
+        let mut reversed = reversed;
+        while let Some(current) = node {
+            reversed ^= current.reversed;
+            self.stack.push((current, reversed));
+            node = if reversed { &current.right } else { &current.left };
+        }
+    }
+}
+
+impl<'a, T> Iterator for RopeIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let (node, reversed) = self.stack.pop()?;
+        self.descend(if reversed { &node.left } else { &node.right }, reversed);
+        Some(&node.value)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Rope<T>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    type Item = &'a T;
+    type IntoIter = RopeIter<'a, T>;
+
+    fn into_iter(self) -> RopeIter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T> fmt::Display for Rope<T>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        let values : Vec<String> = self.iter().map(|value| value.to_string()).collect();
+        write!(f, "[{}]", values.join(","))
+    }
+}
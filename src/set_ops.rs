@@ -0,0 +1,96 @@
+use core::cmp::Ordering;
+use core::iter::Peekable;
+
+//Which elements a SetOperation yields.
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+/**
+* Lazy set operation over two sorted sequences.
+*
+* Both inputs must be sorted in ascending order, for example a sorted DoublyLinkedList and a
+* sorted LinkedNode. Elements are compared pairwise as they are requested, so each input is
+* walked once. When both inputs hold an equal element the one from the first input is yielded.
+*/
+pub struct SetOperation<'a, T, A, B>
+    where T : Ord + 'a, A : Iterator<Item = &'a T>, B : Iterator<Item = &'a T>
+{
+    a : Peekable<A>,
+    b : Peekable<B>,
+    kind : Kind,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+fn new_operation<'a, T, A, B>(a : A, b : B, kind : Kind) -> SetOperation<'a, T, A::IntoIter, B::IntoIter>
+    where T : Ord + 'a, A : IntoIterator<Item = &'a T>, B : IntoIterator<Item = &'a T>
+{
+    SetOperation { a : a.into_iter().peekable(), b : b.into_iter().peekable(), kind }
+}
+
+//Elements that are in a or in b.
+pub fn union<'a, T, A, B>(a : A, b : B) -> SetOperation<'a, T, A::IntoIter, B::IntoIter>
+    where T : Ord + 'a, A : IntoIterator<Item = &'a T>, B : IntoIterator<Item = &'a T>
+{
+    new_operation(a, b, Kind::Union)
+}
+
+//Elements that are both in a and in b.
+pub fn intersection<'a, T, A, B>(a : A, b : B) -> SetOperation<'a, T, A::IntoIter, B::IntoIter>
+    where T : Ord + 'a, A : IntoIterator<Item = &'a T>, B : IntoIterator<Item = &'a T>
+{
+    new_operation(a, b, Kind::Intersection)
+}
+
+//Elements that are in a but not in b.
+pub fn difference<'a, T, A, B>(a : A, b : B) -> SetOperation<'a, T, A::IntoIter, B::IntoIter>
+    where T : Ord + 'a, A : IntoIterator<Item = &'a T>, B : IntoIterator<Item = &'a T>
+{
+    new_operation(a, b, Kind::Difference)
+}
+
+//Elements that are in exactly one of a and b.
+pub fn symmetric_difference<'a, T, A, B>(a : A, b : B) -> SetOperation<'a, T, A::IntoIter, B::IntoIter>
+    where T : Ord + 'a, A : IntoIterator<Item = &'a T>, B : IntoIterator<Item = &'a T>
+{
+    new_operation(a, b, Kind::SymmetricDifference)
+}
+
+impl<'a, T, A, B> Iterator for SetOperation<'a, T, A, B>
+    where T : Ord + 'a, A : Iterator<Item = &'a T>, B : Iterator<Item = &'a T>
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            let order = match (self.a.peek(), self.b.peek()) {
+                (None, None) => return None,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(a), Some(b)) => a.cmp(b),
+            };
+
+            match (order, self.kind) {
+                (Ordering::Less, Kind::Intersection) => { self.a.next(); }
+                (Ordering::Less, _) => return self.a.next(),
+                (Ordering::Greater, Kind::Union) | (Ordering::Greater, Kind::SymmetricDifference) => return self.b.next(),
+                (Ordering::Greater, _) => { self.b.next(); }
+                (Ordering::Equal, Kind::Union) | (Ordering::Equal, Kind::Intersection) => {
+                    self.b.next();
+                    return self.a.next();
+                }
+                (Ordering::Equal, _) => {
+                    self.a.next();
+                    self.b.next();
+                }
+            }
+        }
+    }
+}
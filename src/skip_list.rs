@@ -0,0 +1,324 @@
+use core::fmt;
+use alloc::{string::{String, ToString}, vec::Vec, vec};
+
+use crate::list::*;
+use crate::random;
+
+const MAX_LEVEL : usize = 32;
+
+//Node 0 is the head, it has no value and MAX_LEVEL links.
+#[derive(Debug, Clone)]
+struct SkipNode<T> {
+    value : Option<T>,
+    next : Vec<Option<usize>>,
+    //width[l] is the number of elements next[l] is ahead of this node.
+    width : Vec<usize>,
+}
+
+/**
+* Skip list whose links know how many elements they skip, so positions can be found in
+* expected O(log n) instead of walking the whole list.
+*
+* Nodes live in a Vec and link to each other by index. A list created with new_ordered()
+* keeps its elements sorted and unique, in that mode append and insert_at are refused.
+*/
+#[derive(Debug, Clone)]
+pub struct SkipList<T : Clone + fmt::Display + core::convert::From<T>> {
+    nodes : Vec<SkipNode<T>>,
+    free : Vec<usize>,
+    level : usize,
+    n : usize,
+    ordered : bool,
+    seed : u64,
+}
+
+pub struct SkipIter<'a, T : Clone + fmt::Display + core::convert::From<T>> {
+    list : &'a SkipList<T>,
+    current : Option<usize>,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+impl<T : Clone + fmt::Display + core::convert::From<T>> SkipList<T> {
+    pub fn new() -> SkipList<T> {
+        let head = SkipNode {
+            value : None, next : vec![None; MAX_LEVEL], width : vec![0; MAX_LEVEL],
+        };
+
+        SkipList {
+            nodes : vec![head], free : Vec::new(), level : 1, n : 0, ordered : false,
+            seed : random::seed(),
+        }
+    }
+
+    //Creates a list that works as an ordered set, see insert_ordered.
+    pub fn new_ordered() -> SkipList<T> {
+        let mut list = SkipList::new();
+        list.ordered = true;
+        list
+    }
+
+    pub fn is_ordered(&self) -> bool {
+        self.ordered
+    }
+
+    pub fn iter(&self) -> SkipIter<'_, T> {
+        SkipIter { list : self, current : self.nodes[0].next[0] }
+    }
+
+    //Each level is kept with probability 1/2 (xorshift64).
+    fn random_level(&mut self) -> usize {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        (self.seed.trailing_ones() as usize + 1).min(MAX_LEVEL)
+    }
+
+    /**
+    * Finds, for every level, the last node before position and that node's position.
+    * Positions start at 1 for the first element, the head is position 0.
+    */
+    fn predecessors(&self, position : usize) -> ([usize; MAX_LEVEL], [usize; MAX_LEVEL]) {
+        let mut update = [0; MAX_LEVEL];
+        let mut update_pos = [0; MAX_LEVEL];
+        let mut node = 0;
+        let mut pos = 0;
+
+        for l in (0..self.level).rev() {
+            while let Some(next) = self.nodes[node].next[l] {
+                if pos + self.nodes[node].width[l] >= position { break; }
+                pos += self.nodes[node].width[l];
+                node = next;
+            }
+            update[l] = node;
+            update_pos[l] = pos;
+        }
+
+        (update, update_pos)
+    }
+
+    //Index of the node at position, O(log n) expected.
+    fn node_at(&self, position : usize) -> usize {
+        let mut node = 0;
+        let mut pos = 0;
+
+        for l in (0..self.level).rev() {
+            while let Some(next) = self.nodes[node].next[l] {
+                if pos + self.nodes[node].width[l] > position { break; }
+                pos += self.nodes[node].width[l];
+                node = next;
+            }
+            if pos == position { break; }
+        }
+
+        node
+    }
+
+    fn allocate(&mut self, node : SkipNode<T>) -> usize {
+        match self.free.pop() {
+            Some(index) => { self.nodes[index] = node; index }
+            None => { self.nodes.push(node); self.nodes.len() - 1 }
+        }
+    }
+
+    fn insert_position(&mut self, value : T, index : usize) {
+        let height = self.random_level();
+        if height > self.level {
+            for l in self.level..height {
+                self.nodes[0].next[l] = None;
+            }
+            self.level = height;
+        }
+
+        let (update, update_pos) = self.predecessors(index + 1);
+        let new_node = self.allocate(SkipNode {
+            value : Some(value), next : vec![None; height], width : vec![0; height],
+        });
+
+        for l in 0..(self.level) {
+            let prev = update[l];
+            if l < height {
+                let next = self.nodes[prev].next[l];
+                if next.is_some() {
+                    self.nodes[new_node].width[l] = update_pos[l] + self.nodes[prev].width[l] - index;
+                }
+                self.nodes[new_node].next[l] = next;
+                self.nodes[prev].next[l] = Some(new_node);
+                self.nodes[prev].width[l] = index + 1 - update_pos[l];
+            } else if self.nodes[prev].next[l].is_some() {
+                self.nodes[prev].width[l] += 1;
+            }
+        }
+
+        self.n += 1;
+    }
+
+    fn remove_position(&mut self, index : usize) -> T {
+        let (update, _) = self.predecessors(index + 1);
+        let target = self.nodes[update[0]].next[0].unwrap();
+
+        for (l, &prev) in update.iter().enumerate().take(self.level) {
+            if self.nodes[prev].next[l] == Some(target) {
+                self.nodes[prev].next[l] = self.nodes[target].next[l];
+                if self.nodes[target].next[l].is_some() {
+                    self.nodes[prev].width[l] += self.nodes[target].width[l] - 1;
+                }
+            } else if self.nodes[prev].next[l].is_some() {
+                self.nodes[prev].width[l] -= 1;
+            }
+        }
+
+        while self.level > 1 && self.nodes[0].next[self.level - 1].is_none() {
+            self.level -= 1;
+        }
+
+        self.n -= 1;
+        self.free.push(target);
+        let node = &mut self.nodes[target];
+        node.next = Vec::new();
+        node.width = Vec::new();
+        node.value.take().unwrap()
+    }
+}
+
+//Ordered set mode.
+impl<T : Ord + Clone + fmt::Display + core::convert::From<T>> SkipList<T> {
+    //Number of elements smaller than value, found in O(log n) expected.
+    fn rank(&self, value : &T) -> usize {
+        let mut node = 0;
+        let mut pos = 0;
+
+        for l in (0..self.level).rev() {
+            while let Some(next) = self.nodes[node].next[l] {
+                if self.nodes[next].value.as_ref().unwrap() >= value { break; }
+                pos += self.nodes[node].width[l];
+                node = next;
+            }
+        }
+
+        pos
+    }
+
+    /**
+    * @brief Inserts value where it keeps the list sorted, only in ordered mode.
+    *
+    * This method is implemented in O(log n) expected time.
+    *
+    * @return u8 0 on success, 1 if value was already in the list or the list is not ordered.
+    */
+    pub fn insert_ordered(&mut self, value : T) -> u8 {
+        if !self.ordered {
+            return 1;
+        }
+
+        let index = self.rank(&value);
+        if index < self.n && *self.value_at(index) == value {
+            return 1;
+        }
+
+        self.insert_position(value, index);
+        0
+    }
+
+    //Index of value, in ordered mode it takes O(log n) expected time.
+    pub fn index_of(&self, value : &T) -> Option<usize> {
+        if !self.ordered {
+            return self.iter().position(|element| element == value);
+        }
+
+        let index = self.rank(value);
+        if index < self.n && self.value_at(index) == value { Some(index) } else { None }
+    }
+
+    pub fn contains(&self, value : &T) -> bool {
+        self.index_of(value).is_some()
+    }
+
+    //Removes the first element equal to value, returns true if there was one.
+    pub fn remove_value(&mut self, value : &T) -> bool {
+        match self.index_of(value) {
+            Some(index) => { self.remove_position(index); true }
+            None => false,
+        }
+    }
+}
+
+impl<T : Clone + fmt::Display + core::convert::From<T>> Default for SkipList<T> {
+    fn default() -> Self {
+        SkipList::new()
+    }
+}
+
+impl<T> List<T> for SkipList<T>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    //Returns 1 in ordered mode, use insert_ordered instead.
+    fn append(&mut self, value : T) -> u8 {
+        if self.ordered {
+            return 1;
+        }
+        self.insert_position(value, self.n);
+        0
+    }
+
+    //O(log n) expected. Returns 1 in ordered mode, use insert_ordered instead.
+    fn insert_at(&mut self, value : T, index : usize) -> u8 {
+        if index > self.n {
+            panic!("Index out of bounds in function insert_at.");
+        }
+        if self.ordered {
+            return 1;
+        }
+        self.insert_position(value, index);
+        0
+    }
+
+    //O(log n) expected.
+    fn remove_at(&mut self, index : usize) -> u8 {
+        if self.n == 0 { return 1; }
+        if index >= self.n { return 2; }
+
+        self.remove_position(index);
+        0
+    }
+
+    //O(log n) expected.
+    fn value_at(&self, index : usize) -> &T {
+        if index >= self.n {panic!("Invalid index")};
+        self.nodes[self.node_at(index + 1)].value.as_ref().unwrap()
+    }
+
+    //Changing the value in ordered mode may break the order.
+    fn mut_value_at(&mut self, index : usize) -> &mut T {
+        if index >= self.n {panic!("Invalid index")};
+        let node = self.node_at(index + 1);
+        self.nodes[node].value.as_mut().unwrap()
+    }
+
+    fn size(&self) -> usize {
+        self.n
+    }
+}
+
+impl<'a, T> Iterator for SkipIter<'a, T>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = &self.list.nodes[self.current?];
+        self.current = node.next[0];
+        node.value.as_ref()
+    }
+}
+
+impl<T> fmt::Display for SkipList<T>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        let values : Vec<String> = self.iter().map(|value| value.to_string()).collect();
+        write!(f, "[{}]", values.join(","))
+    }
+}
@@ -0,0 +1,175 @@
+use core::fmt;
+use core::ops::{Bound, RangeBounds};
+
+use crate::list::*;
+
+//What SortedList::insert does with a value equal to one already stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    //Keeps both, the new value goes after the equal ones.
+    Allow,
+    //Keeps the stored value and discards the new one.
+    Reject,
+    //Stores the new value in place of the first equal one.
+    Replace,
+}
+
+/**
+* DoublyLinkedList that keeps its elements in ascending order.
+*
+* Only operations that preserve the order are exposed, so there is no insert_at or append.
+*/
+#[derive(Clone)]
+pub struct SortedList<T : Ord + Clone + fmt::Display + core::convert::From<T>> {
+    list : DoublyLinkedList<T>,
+    policy : DuplicatePolicy,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+impl<T : Ord + Clone + fmt::Display + core::convert::From<T>> SortedList<T> {
+    pub fn new() -> SortedList<T> {
+        SortedList::with_policy(DuplicatePolicy::Allow)
+    }
+
+    pub fn with_policy(policy : DuplicatePolicy) -> SortedList<T> {
+        SortedList {
+            list : DoublyLinkedList::new(), policy,
+        }
+    }
+
+    pub fn policy(&self) -> DuplicatePolicy {
+        self.policy
+    }
+
+    /**
+    * @brief Inserts an element where it keeps the list sorted.
+    *
+    * This method is implemented in O(n) for time and O(1) for memory.
+    *
+    * @param T value Element to be inserted.
+    *
+    * @return u8 0 on success, 1 if the value was rejected as a duplicate.
+    */
+    pub fn insert(&mut self, value : T) -> u8 {
+        if self.policy == DuplicatePolicy::Allow {
+            let index = self.list.position(|element| *element > value).unwrap_or(self.list.size());
+            self.list.insert_at(value, index);
+            return 0;
+        }
+
+        let index = self.list.position(|element| *element >= value).unwrap_or(self.list.size());
+        if index < self.list.size() && *self.list.value_at(index) == value {
+            if self.policy == DuplicatePolicy::Reject {
+                return 1;
+            }
+            *self.list.mut_value_at(index) = value;
+            return 0;
+        }
+
+        self.list.insert_at(value, index);
+        0
+    }
+
+    /**
+    * @brief Removes the first element equal to value.
+    *
+    * @return bool true if an element was removed.
+    */
+    pub fn remove(&mut self, value : &T) -> bool {
+        match self.list.position(|element| element >= value) {
+            Some(index) if self.list.value_at(index) == value => {
+                self.list.remove_at(index);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn contains(&self, value : &T) -> bool {
+        self.list.binary_search_by(|element| element.cmp(value)).is_ok()
+    }
+
+    //The smallest element.
+    pub fn first(&self) -> Option<&T> {
+        if self.list.size() == 0 { None } else { Some(self.list.value_at(0)) }
+    }
+
+    //The biggest element.
+    pub fn last(&self) -> Option<&T> {
+        if self.list.size() == 0 { None } else { Some(self.list.value_at(self.list.size() - 1)) }
+    }
+
+    pub fn size(&self) -> usize {
+        self.list.size()
+    }
+
+    pub fn empty(&self) -> bool {
+        self.list.size() == 0
+    }
+
+    pub fn iter(&self) -> DoublyIter<'_, T> {
+        self.list.iter()
+    }
+
+    /**
+    * @brief Iterates in order over the elements inside range.
+    *
+    * @param R range For example lo..hi or ..=hi.
+    */
+    pub fn range<R : RangeBounds<T>>(&self, range : R) -> impl Iterator<Item = &T> {
+        let start = range.start_bound().cloned();
+        let end = range.end_bound().cloned();
+
+        self.list.iter()
+            .skip_while(move |element| match &start {
+                Bound::Included(start) => *element < start,
+                Bound::Excluded(start) => *element <= start,
+                Bound::Unbounded => false,
+            })
+            .take_while(move |element| match &end {
+                Bound::Included(end) => *element <= end,
+                Bound::Excluded(end) => *element < end,
+                Bound::Unbounded => true,
+            })
+    }
+
+    /**
+    * @brief Removes the smallest element.
+    *
+    * @return T The removed element. Panics if the list is empty, like Deque::pop_front.
+    */
+    pub fn pop_front(&mut self) -> T {
+        self.list.pop_front()
+    }
+
+    /**
+    * @brief Removes the biggest element.
+    *
+    * @return T The removed element. Panics if the list is empty, like Deque::pop_back.
+    */
+    pub fn pop_back(&mut self) -> T {
+        self.list.pop_back()
+    }
+
+    //Removes every element.
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+}
+
+impl<T : Ord + Clone + fmt::Display + core::convert::From<T>> Default for SortedList<T> {
+    fn default() -> Self {
+        SortedList::new()
+    }
+}
+
+impl<T> fmt::Display for SortedList<T>
+    where T : Ord + Clone + fmt::Display + core::convert::From<T>
+{
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.list)
+    }
+}
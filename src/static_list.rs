@@ -0,0 +1,321 @@
+use core::fmt;
+use core::mem::MaybeUninit;
+
+use crate::list::*;
+
+//No node, any real slot is smaller than N.
+const NIL : usize = usize::MAX;
+
+/**
+* Doubly linked list of at most N elements stored inline, it never allocates.
+*
+* Nodes are slots of fixed arrays linked by index, removed slots are reused. try_push_back
+* and try_insert_at give the value back when the list is full, while List::append and
+* List::insert_at return 1 and Deque::push_back and Deque::push_front panic.
+*/
+pub struct StaticList<T : Clone + fmt::Display + core::convert::From<T>, const N : usize> {
+    values : [MaybeUninit<T>; N],
+    prev : [usize; N],
+    //For a free slot, the next free slot.
+    next : [usize; N],
+    head : usize,
+    tail : usize,
+    //First free slot that was used before, the slots from used onwards were never used.
+    free : usize,
+    used : usize,
+    n : usize,
+}
+
+pub struct StaticIter<'a, T : Clone + fmt::Display + core::convert::From<T>, const N : usize> {
+    list : &'a StaticList<T, N>,
+    front : usize,
+    back : usize,
+    remaining : usize,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+impl<T : Clone + fmt::Display + core::convert::From<T>, const N : usize> StaticList<T, N> {
+    pub const fn new() -> StaticList<T, N> {
+        StaticList {
+            values : [const { MaybeUninit::uninit() }; N], prev : [NIL; N], next : [NIL; N],
+            head : NIL, tail : NIL, free : NIL, used : 0, n : 0,
+        }
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.n == N
+    }
+
+    pub fn iter(&self) -> StaticIter<'_, T, N> {
+        StaticIter { list : self, front : self.head, back : self.tail, remaining : self.n }
+    }
+
+    /**
+    * @brief Inserts an element to the end of the list if there is room for it.
+    *
+    * @return Result<(), T> Err with the value if the list is full.
+    */
+    pub fn try_push_back(&mut self, value : T) -> Result<(), T> {
+        self.insert_position(value, self.n)
+    }
+
+    /**
+    * @brief Inserts an element to the beginning of the list if there is room for it.
+    *
+    * @return Result<(), T> Err with the value if the list is full.
+    */
+    pub fn try_push_front(&mut self, value : T) -> Result<(), T> {
+        self.insert_position(value, 0)
+    }
+
+    /**
+    * @brief Inserts an element so it ends up at index if there is room for it.
+    *
+    * Panics if index is bigger than the size, like List::insert_at.
+    *
+    * @return Result<(), T> Err with the value if the list is full.
+    */
+    pub fn try_insert_at(&mut self, value : T, index : usize) -> Result<(), T> {
+        if index > self.n {
+            panic!("Index out of bounds in function try_insert_at.");
+        }
+        self.insert_position(value, index)
+    }
+
+    //Removes every element.
+    pub fn clear(&mut self) {
+        while self.n > 0 {
+            self.remove_position(0);
+        }
+    }
+
+    fn value(&self, slot : usize) -> &T {
+        unsafe { self.values[slot].assume_init_ref() }
+    }
+
+    //Slot of the element at index, walking from the closest end.
+    fn slot_at(&self, index : usize) -> usize {
+        if index * 2 < self.n {
+            let mut slot = self.head;
+            for _ in 0..index { slot = self.next[slot]; }
+            slot
+        } else {
+            let mut slot = self.tail;
+            for _ in index..(self.n - 1) { slot = self.prev[slot]; }
+            slot
+        }
+    }
+
+    fn insert_position(&mut self, value : T, index : usize) -> Result<(), T> {
+        let slot = if self.free != NIL {
+            let slot = self.free;
+            self.free = self.next[slot];
+            slot
+        } else if self.used < N {
+            self.used += 1;
+            self.used - 1
+        } else {
+            return Err(value);
+        };
+        self.values[slot].write(value);
+
+        //The new element goes between prev and next.
+        let next = if index == self.n { NIL } else { self.slot_at(index) };
+        let prev = if next == NIL { self.tail } else { self.prev[next] };
+
+        self.prev[slot] = prev;
+        self.next[slot] = next;
+        if prev == NIL { self.head = slot; } else { self.next[prev] = slot; }
+        if next == NIL { self.tail = slot; } else { self.prev[next] = slot; }
+        self.n += 1;
+
+        Ok(())
+    }
+
+    fn remove_position(&mut self, index : usize) -> T {
+        let slot = self.slot_at(index);
+        let (prev, next) = (self.prev[slot], self.next[slot]);
+
+        if prev == NIL { self.head = next; } else { self.next[prev] = next; }
+        if next == NIL { self.tail = prev; } else { self.prev[next] = prev; }
+        self.n -= 1;
+
+        self.next[slot] = self.free;
+        self.free = slot;
+        unsafe { self.values[slot].assume_init_read() }
+    }
+}
+
+impl<T : Clone + fmt::Display + core::convert::From<T>, const N : usize> Default for StaticList<T, N> {
+    fn default() -> Self {
+        StaticList::new()
+    }
+}
+
+impl<T : Clone + fmt::Display + core::convert::From<T>, const N : usize> Clone for StaticList<T, N> {
+    fn clone(&self) -> Self {
+        let mut list = StaticList::new();
+        for value in self.iter() {
+            let _ = list.try_push_back(value.clone());
+        }
+        list
+    }
+}
+
+impl<T : Clone + fmt::Display + core::convert::From<T>, const N : usize> Drop for StaticList<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N : usize> List<T> for StaticList<T, N>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    //Returns 1 if the list is full.
+    fn append(&mut self, value : T) -> u8 {
+        match self.insert_position(value, self.n) {
+            Ok(()) => 0,
+            Err(_) => 1,
+        }
+    }
+
+    //O(min(index, n - index)) for time. Returns 1 if the list is full.
+    fn insert_at(&mut self, value : T, index : usize) -> u8 {
+        match self.try_insert_at(value, index) {
+            Ok(()) => 0,
+            Err(_) => 1,
+        }
+    }
+
+    fn remove_at(&mut self, index : usize) -> u8 {
+        if self.n == 0 { return 1; }
+        if index >= self.n { return 2; }
+
+        self.remove_position(index);
+        0
+    }
+
+    fn value_at(&self, index : usize) -> &T {
+        if index >= self.n {panic!("Invalid index at value_at function.")};
+        self.value(self.slot_at(index))
+    }
+
+    fn mut_value_at(&mut self, index : usize) -> &mut T {
+        if index >= self.n {panic!("Invalid index at mut_value_at function.")};
+        let slot = self.slot_at(index);
+        unsafe { self.values[slot].assume_init_mut() }
+    }
+
+    fn size(&self) -> usize {
+        self.n
+    }
+}
+
+impl<T, const N : usize> Deque<T> for StaticList<T, N>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    fn empty(&self) -> bool {
+        self.n == 0
+    }
+
+    //Panics if the list is full, see try_push_back.
+    fn push_back(&mut self, value : T) -> &mut Self {
+        if self.try_push_back(value).is_err() {panic!("Full list at push_back function.")};
+        self
+    }
+
+    fn pop_back(&mut self) -> T {
+        if self.n == 0 {panic!("Empty list at pop_back function.")};
+        self.remove_position(self.n - 1)
+    }
+
+    //Panics if the list is full, see try_push_front.
+    fn push_front(&mut self, value : T) -> &mut Self {
+        if self.try_push_front(value).is_err() {panic!("Full list at push_front function.")};
+        self
+    }
+
+    fn pop_front(&mut self) -> T {
+        if self.n == 0 {panic!("Empty list at pop_front function.")};
+        self.remove_position(0)
+    }
+}
+
+impl<T, const N : usize> Reversible for StaticList<T, N>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    fn reverse(&mut self) -> &mut Self {
+        let mut slot = self.head;
+        while slot != NIL {
+            core::mem::swap(&mut self.next[slot], &mut self.prev[slot]);
+            slot = self.prev[slot];
+        }
+        core::mem::swap(&mut self.head, &mut self.tail);
+
+        self
+    }
+}
+
+impl<'a, T, const N : usize> Iterator for StaticIter<'a, T, N>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 { return None; }
+        self.remaining -= 1;
+
+        let slot = self.front;
+        self.front = self.list.next[slot];
+        Some(self.list.value(slot))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, const N : usize> DoubleEndedIterator for StaticIter<'a, T, N>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 { return None; }
+        self.remaining -= 1;
+
+        let slot = self.back;
+        self.back = self.list.prev[slot];
+        Some(self.list.value(slot))
+    }
+}
+
+impl<'a, T, const N : usize> IntoIterator for &'a StaticList<T, N>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    type Item = &'a T;
+    type IntoIter = StaticIter<'a, T, N>;
+
+    fn into_iter(self) -> StaticIter<'a, T, N> {
+        self.iter()
+    }
+}
+
+//Written element by element so formatting doesn't need a String.
+impl<T, const N : usize> fmt::Display for StaticList<T, N>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, value) in self.iter().enumerate() {
+            if i > 0 { write!(f, ",")?; }
+            write!(f, "{}", value)?;
+        }
+        write!(f, "]")
+    }
+}
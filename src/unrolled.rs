@@ -0,0 +1,396 @@
+use core::fmt;
+use core::mem::MaybeUninit;
+use core::ptr;
+use core::slice;
+use alloc::{string::{String, ToString}, vec::Vec};
+
+use crate::list::*;
+
+//A node of the list, values[..len] are initialized.
+struct Chunk<T, const N : usize> {
+    values : [MaybeUninit<T>; N],
+    len : usize,
+    prev : Option<usize>,
+    next : Option<usize>,
+}
+
+/**
+* Doubly linked list whose nodes hold up to N elements in an inline array.
+*
+* Small elements share an allocation instead of paying for two pointers each, and iteration
+* walks contiguous memory. Nodes are split when an insertion finds them full and merged with a
+* neighbour when a removal leaves them less than half full. Nodes live in a Vec and link to each
+* other by index.
+*/
+pub struct UnrolledList<T : Clone + fmt::Display + core::convert::From<T>, const N : usize = 16> {
+    chunks : Vec<Chunk<T, N>>,
+    free : Vec<usize>,
+    head : Option<usize>,
+    tail : Option<usize>,
+    n : usize,
+}
+
+pub struct UnrolledIter<'a, T : Clone + fmt::Display + core::convert::From<T>, const N : usize> {
+    list : &'a UnrolledList<T, N>,
+    chunk : Option<usize>,
+    offset : usize,
+    remaining : usize,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+impl<T, const N : usize> Chunk<T, N> {
+    fn new() -> Chunk<T, N> {
+        Chunk {
+            values : core::array::from_fn(|_| MaybeUninit::uninit()), len : 0, prev : None, next : None,
+        }
+    }
+
+    fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.values.as_ptr() as *const T, self.len) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.values.as_mut_ptr() as *mut T, self.len) }
+    }
+
+    //The chunk must not be full.
+    fn insert(&mut self, offset : usize, value : T) {
+        unsafe {
+            let base = self.values.as_mut_ptr();
+            ptr::copy(base.add(offset), base.add(offset + 1), self.len - offset);
+        }
+        self.values[offset].write(value);
+        self.len += 1;
+    }
+
+    fn remove(&mut self, offset : usize) -> T {
+        unsafe {
+            let value = self.values[offset].assume_init_read();
+            let base = self.values.as_mut_ptr();
+            ptr::copy(base.add(offset + 1), base.add(offset), self.len - offset - 1);
+            self.len -= 1;
+            value
+        }
+    }
+
+    //Moves values[at..] to the end of other, other must have room for them.
+    fn move_to(&mut self, at : usize, other : &mut Chunk<T, N>) {
+        let count = self.len - at;
+        unsafe {
+            ptr::copy_nonoverlapping(self.values.as_ptr().add(at), other.values.as_mut_ptr().add(other.len), count);
+        }
+        self.len = at;
+        other.len += count;
+    }
+}
+
+impl<T, const N : usize> Drop for Chunk<T, N> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl<T : Clone + fmt::Display + core::convert::From<T>, const N : usize> UnrolledList<T, N> {
+    pub fn new() -> UnrolledList<T, N> {
+        if N < 2 {
+            panic!("UnrolledList needs room for at least 2 elements per node.");
+        }
+
+        UnrolledList {
+            chunks : Vec::new(), free : Vec::new(), head : None, tail : None, n : 0,
+        }
+    }
+
+    //Number of nodes in use, it stays between n / N and about 2n / N.
+    pub fn node_count(&self) -> usize {
+        self.chunks.len() - self.free.len()
+    }
+
+    pub fn iter(&self) -> UnrolledIter<'_, T, N> {
+        UnrolledIter { list : self, chunk : self.head, offset : 0, remaining : self.n }
+    }
+
+    fn allocate(&mut self) -> usize {
+        match self.free.pop() {
+            Some(index) => index,
+            None => { self.chunks.push(Chunk::new()); self.chunks.len() - 1 }
+        }
+    }
+
+    //Creates an empty chunk right after chunk, or as the only one if chunk is None.
+    fn link_after(&mut self, chunk : Option<usize>) -> usize {
+        let new_chunk = self.allocate();
+        let next = match chunk {
+            Some(chunk) => self.chunks[chunk].next,
+            None => None,
+        };
+
+        self.chunks[new_chunk].prev = chunk;
+        self.chunks[new_chunk].next = next;
+        match chunk {
+            Some(chunk) => self.chunks[chunk].next = Some(new_chunk),
+            None => self.head = Some(new_chunk),
+        }
+        match next {
+            Some(next) => self.chunks[next].prev = Some(new_chunk),
+            None => self.tail = Some(new_chunk),
+        }
+
+        new_chunk
+    }
+
+    //The chunk must already be empty.
+    fn unlink(&mut self, chunk : usize) {
+        let (prev, next) = (self.chunks[chunk].prev, self.chunks[chunk].next);
+        match prev {
+            Some(prev) => self.chunks[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.chunks[next].prev = prev,
+            None => self.tail = prev,
+        }
+        self.free.push(chunk);
+    }
+
+    /**
+    * Finds the chunk holding index and the offset inside it, walking from the closest end.
+    * An index equal to the size gives the position after the last element.
+    */
+    fn locate(&self, index : usize) -> (usize, usize) {
+        if index * 2 <= self.n {
+            let mut chunk = self.head.unwrap();
+            let mut offset = index;
+            while offset >= self.chunks[chunk].len && self.chunks[chunk].next.is_some() {
+                offset -= self.chunks[chunk].len;
+                chunk = self.chunks[chunk].next.unwrap();
+            }
+            (chunk, offset)
+        } else {
+            let mut chunk = self.tail.unwrap();
+            let mut before = self.n - self.chunks[chunk].len;
+            while before > index {
+                chunk = self.chunks[chunk].prev.unwrap();
+                before -= self.chunks[chunk].len;
+            }
+            (chunk, index - before)
+        }
+    }
+
+    fn insert_position(&mut self, value : T, index : usize) {
+        if self.n == 0 {
+            let chunk = self.link_after(None);
+            self.chunks[chunk].insert(0, value);
+            self.n += 1;
+            return;
+        }
+
+        let (mut chunk, mut offset) = self.locate(index);
+        if self.chunks[chunk].len == N {
+            //Half of the values go to a new chunk.
+            let new_chunk = self.link_after(Some(chunk));
+            let (left, right) = two_mut(&mut self.chunks, chunk, new_chunk);
+            left.move_to(N / 2, right);
+
+            if offset > N / 2 {
+                chunk = new_chunk;
+                offset -= N / 2;
+            }
+        }
+
+        self.chunks[chunk].insert(offset, value);
+        self.n += 1;
+    }
+
+    fn remove_position(&mut self, index : usize) -> T {
+        let (chunk, offset) = self.locate(index);
+        let value = self.chunks[chunk].remove(offset);
+        self.n -= 1;
+
+        let len = self.chunks[chunk].len;
+        if len == 0 {
+            self.unlink(chunk);
+        } else if len < N / 2 {
+            //Merges with a neighbour when both fit in one chunk.
+            if let Some(next) = self.chunks[chunk].next {
+                if len + self.chunks[next].len <= N {
+                    let (left, right) = two_mut(&mut self.chunks, next, chunk);
+                    left.move_to(0, right);
+                    self.unlink(next);
+                    return value;
+                }
+            }
+            if let Some(prev) = self.chunks[chunk].prev {
+                if len + self.chunks[prev].len <= N {
+                    let (left, right) = two_mut(&mut self.chunks, chunk, prev);
+                    left.move_to(0, right);
+                    self.unlink(chunk);
+                }
+            }
+        }
+
+        value
+    }
+}
+
+//Mutable references to two different chunks.
+fn two_mut<T, const N : usize>(chunks : &mut [Chunk<T, N>], a : usize, b : usize) -> (&mut Chunk<T, N>, &mut Chunk<T, N>) {
+    if a < b {
+        let (left, right) = chunks.split_at_mut(b);
+        (&mut left[a], &mut right[0])
+    } else {
+        let (left, right) = chunks.split_at_mut(a);
+        (&mut right[0], &mut left[b])
+    }
+}
+
+impl<T : Clone + fmt::Display + core::convert::From<T>, const N : usize> Default for UnrolledList<T, N> {
+    fn default() -> Self {
+        UnrolledList::new()
+    }
+}
+
+impl<T : Clone + fmt::Display + core::convert::From<T>, const N : usize> Clone for UnrolledList<T, N> {
+    fn clone(&self) -> Self {
+        let mut list = UnrolledList::new();
+        for value in self.iter() {
+            list.insert_position(value.clone(), list.n);
+        }
+        list
+    }
+}
+
+impl<T, const N : usize> List<T> for UnrolledList<T, N>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    fn append(&mut self, value : T) -> u8 {
+        self.insert_position(value, self.n);
+        0
+    }
+
+    //O(n / N) for time, the node is found from the closest end.
+    fn insert_at(&mut self, value : T, index : usize) -> u8 {
+        if index > self.n {
+            panic!("Index out of bounds in function insert_at.");
+        }
+        self.insert_position(value, index);
+        0
+    }
+
+    fn remove_at(&mut self, index : usize) -> u8 {
+        if self.n == 0 { return 1; }
+        if index >= self.n { return 2; }
+
+        self.remove_position(index);
+        0
+    }
+
+    fn value_at(&self, index : usize) -> &T {
+        if index >= self.n {panic!("Invalid index at value_at function.")};
+        let (chunk, offset) = self.locate(index);
+        &self.chunks[chunk].as_slice()[offset]
+    }
+
+    fn mut_value_at(&mut self, index : usize) -> &mut T {
+        if index >= self.n {panic!("Invalid index at mut_value_at function.")};
+        let (chunk, offset) = self.locate(index);
+        &mut self.chunks[chunk].as_mut_slice()[offset]
+    }
+
+    fn size(&self) -> usize {
+        self.n
+    }
+}
+
+impl<T, const N : usize> Deque<T> for UnrolledList<T, N>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    fn empty(&self) -> bool {
+        self.n == 0
+    }
+
+    fn push_back(&mut self, value : T) -> &mut Self {
+        self.insert_position(value, self.n);
+        self
+    }
+
+    fn pop_back(&mut self) -> T {
+        if self.n == 0 {panic!("Empty list at pop_back function.")};
+        self.remove_position(self.n - 1)
+    }
+
+    fn push_front(&mut self, value : T) -> &mut Self {
+        self.insert_position(value, 0);
+        self
+    }
+
+    fn pop_front(&mut self) -> T {
+        if self.n == 0 {panic!("Empty list at pop_front function.")};
+        self.remove_position(0)
+    }
+}
+
+impl<T, const N : usize> Reversible for UnrolledList<T, N>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    //Reverses every node and the links between them.
+    fn reverse(&mut self) -> &mut Self {
+        let mut current = self.head;
+        while let Some(chunk) = current {
+            let node = &mut self.chunks[chunk];
+            node.as_mut_slice().reverse();
+            core::mem::swap(&mut node.prev, &mut node.next);
+            current = node.prev;
+        }
+        core::mem::swap(&mut self.head, &mut self.tail);
+
+        self
+    }
+}
+
+impl<'a, T, const N : usize> Iterator for UnrolledIter<'a, T, N>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let chunk = &self.list.chunks[self.chunk?];
+        let value = &chunk.as_slice()[self.offset];
+
+        self.offset += 1;
+        if self.offset == chunk.len {
+            self.chunk = chunk.next;
+            self.offset = 0;
+        }
+        self.remaining -= 1;
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, const N : usize> IntoIterator for &'a UnrolledList<T, N>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    type Item = &'a T;
+    type IntoIter = UnrolledIter<'a, T, N>;
+
+    fn into_iter(self) -> UnrolledIter<'a, T, N> {
+        self.iter()
+    }
+}
+
+impl<T, const N : usize> fmt::Display for UnrolledList<T, N>
+    where T : Clone + fmt::Display + core::convert::From<T>
+{
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        let values : Vec<String> = self.iter().map(|value| value.to_string()).collect();
+        write!(f, "[{}]", values.join(","))
+    }
+}
@@ -0,0 +1,370 @@
+use core::cell::UnsafeCell;
+use core::ptr;
+use core::sync::atomic::{AtomicBool, AtomicIsize, AtomicPtr, Ordering};
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use crate::list::{ConcurrentDeque, OwnedDeque};
+
+const MIN_CAPACITY : usize = 16;
+
+//Circular array of boxed elements, its length is a power of two.
+struct Buffer<T> {
+    slots : Box<[AtomicPtr<T>]>,
+}
+
+//Hazard pointer of one thief, slots are only added to the list and reused until the deque is dropped.
+struct Hazard<T> {
+    //Buffer being read by the thief that holds the slot, null if none.
+    buffer : AtomicPtr<Buffer<T>>,
+    in_use : AtomicBool,
+    next : *mut Hazard<T>,
+}
+
+struct Inner<T> {
+    //Next element to steal.
+    top : AtomicIsize,
+    //Next free slot of the owner's end.
+    bottom : AtomicIsize,
+    buffer : AtomicPtr<Buffer<T>>,
+    //Every hazard slot, as many as thieves ever stole at the same time.
+    hazards : AtomicPtr<Hazard<T>>,
+    //Buffers replaced by a bigger one, only the Worker and the last drop touch it.
+    retired : UnsafeCell<Vec<*mut Buffer<T>>>,
+}
+
+/**
+* Owner side of a lock-free Chase-Lev work-stealing deque.
+*
+* The owner pushes and pops at the back without locks, any number of Stealer handles pop from
+* the front at the same time, so the owner works LIFO while the thieves take the oldest work.
+* Every operation finishes in a bounded number of steps unless another thread made progress.
+*
+* When the buffer grows the old one can still be read by a thief, so it is retired. Buffers are
+* reclaimed with hazard pointers: a thief publishes the buffer it reads in a hazard slot, and the
+* owner frees every retired buffer no slot points to whenever it pushes. Thieves only publish the
+* buffer they are reading, so old buffers are freed even while other thieves steal nonstop.
+*/
+pub struct Worker<T> {
+    inner : Arc<Inner<T>>,
+}
+
+//Thief side of the deque, it can be cloned and sent to other threads.
+pub struct Stealer<T> {
+    inner : Arc<Inner<T>>,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+//Elements are moved between threads and the buffers are only shared through atomics.
+unsafe impl<T : Send> Send for Inner<T> {}
+unsafe impl<T : Send> Sync for Inner<T> {}
+
+impl<T> Buffer<T> {
+    fn new(capacity : usize) -> *mut Buffer<T> {
+        let slots = (0..capacity).map(|_| AtomicPtr::new(ptr::null_mut())).collect();
+        Box::into_raw(Box::new(Buffer { slots }))
+    }
+
+    fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    fn slot(&self, index : isize) -> &AtomicPtr<T> {
+        &self.slots[index as usize & (self.slots.len() - 1)]
+    }
+}
+
+impl<T> Inner<T> {
+    fn size(&self) -> usize {
+        let bottom = self.bottom.load(Ordering::SeqCst);
+        let top = self.top.load(Ordering::SeqCst);
+        (bottom - top).max(0) as usize
+    }
+
+    //Frees the retired buffers that no hazard slot points to, only the Worker calls it.
+    unsafe fn collect(&self) {
+        let retired = &mut *self.retired.get();
+        if retired.is_empty() {
+            return;
+        }
+
+        retired.retain(|&buffer| {
+            let mut hazard = self.hazards.load(Ordering::SeqCst);
+            while !hazard.is_null() {
+                if (*hazard).buffer.load(Ordering::SeqCst) == buffer {
+                    return true;
+                }
+                hazard = (*hazard).next;
+            }
+
+            drop(Box::from_raw(buffer));
+            false
+        });
+    }
+
+    //A hazard slot for the calling thief, a new one is added if all of them are taken.
+    fn acquire(&self) -> &Hazard<T> {
+        let mut hazard = self.hazards.load(Ordering::SeqCst);
+        while !hazard.is_null() {
+            let slot = unsafe { &*hazard };
+            if slot.in_use.compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst).is_ok() {
+                return slot;
+            }
+            hazard = slot.next;
+        }
+
+        let slot = Box::into_raw(Box::new(Hazard {
+            buffer : AtomicPtr::new(ptr::null_mut()), in_use : AtomicBool::new(true), next : ptr::null_mut(),
+        }));
+        let mut head = self.hazards.load(Ordering::SeqCst);
+        loop {
+            unsafe { (*slot).next = head; }
+            match self.hazards.compare_exchange(head, slot, Ordering::SeqCst, Ordering::SeqCst) {
+                Ok(_) => return unsafe { &*slot },
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    //Loads the current buffer and publishes it in hazard, so it isn't freed until the slot is cleared.
+    fn protect(&self, hazard : &Hazard<T>) -> *mut Buffer<T> {
+        loop {
+            let buffer = self.buffer.load(Ordering::SeqCst);
+            hazard.buffer.store(buffer, Ordering::SeqCst);
+            //The owner retires a buffer before scanning the slots, so if it is still current it's safe.
+            if self.buffer.load(Ordering::SeqCst) == buffer {
+                return buffer;
+            }
+        }
+    }
+}
+
+impl<T> Hazard<T> {
+    fn release(&self) {
+        self.buffer.store(ptr::null_mut(), Ordering::SeqCst);
+        self.in_use.store(false, Ordering::SeqCst);
+    }
+}
+
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let buffer = Box::from_raw(*self.buffer.get_mut());
+            for index in *self.top.get_mut()..*self.bottom.get_mut() {
+                drop(Box::from_raw(buffer.slot(index).load(Ordering::Relaxed)));
+            }
+            for retired in self.retired.get_mut().drain(..) {
+                drop(Box::from_raw(retired));
+            }
+            let mut hazard = *self.hazards.get_mut();
+            while !hazard.is_null() {
+                hazard = Box::from_raw(hazard).next;
+            }
+        }
+    }
+}
+
+impl<T> Worker<T> {
+    pub fn new() -> Worker<T> {
+        Worker {
+            inner : Arc::new(Inner {
+                top : AtomicIsize::new(0), bottom : AtomicIsize::new(0),
+                buffer : AtomicPtr::new(Buffer::new(MIN_CAPACITY)),
+                hazards : AtomicPtr::new(ptr::null_mut()), retired : UnsafeCell::new(Vec::new()),
+            }),
+        }
+    }
+
+    //A new handle to steal from this deque.
+    pub fn stealer(&self) -> Stealer<T> {
+        Stealer { inner : self.inner.clone() }
+    }
+
+    //Only a guess while thieves are working.
+    pub fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    pub fn empty(&self) -> bool {
+        self.inner.size() == 0
+    }
+
+    //Pushes an element to the back, the buffer doubles when it's full.
+    pub fn push_back(&mut self, value : T) -> &mut Self {
+        let inner = &*self.inner;
+        let bottom = inner.bottom.load(Ordering::SeqCst);
+        let top = inner.top.load(Ordering::SeqCst);
+        let mut buffer = inner.buffer.load(Ordering::SeqCst);
+
+        unsafe {
+            //Thieves that were reading a retired buffer have usually finished by now.
+            inner.collect();
+            if bottom - top >= (*buffer).capacity() as isize {
+                buffer = self.grow(buffer, top, bottom);
+            }
+            (*buffer).slot(bottom).store(Box::into_raw(Box::new(value)), Ordering::SeqCst);
+        }
+        inner.bottom.store(bottom + 1, Ordering::SeqCst);
+
+        self
+    }
+
+    //Only called from push_back, so the owner is the only one touching retired.
+    unsafe fn grow(&self, old : *mut Buffer<T>, top : isize, bottom : isize) -> *mut Buffer<T> {
+        let inner = &*self.inner;
+        let new = Buffer::new((*old).capacity() * 2);
+        for index in top..bottom {
+            (*new).slot(index).store((*old).slot(index).load(Ordering::SeqCst), Ordering::SeqCst);
+        }
+
+        inner.buffer.store(new, Ordering::SeqCst);
+        (*inner.retired.get()).push(old);
+        inner.collect();
+        new
+    }
+
+    /**
+    * @brief Pops the last element, the most recently pushed one.
+    *
+    * @return Option<T> None if the deque is empty or a thief took the last element.
+    */
+    pub fn pop_back(&mut self) -> Option<T> {
+        let inner = &*self.inner;
+        let bottom = inner.bottom.load(Ordering::SeqCst) - 1;
+        let buffer = inner.buffer.load(Ordering::SeqCst);
+        inner.bottom.store(bottom, Ordering::SeqCst);
+        let top = inner.top.load(Ordering::SeqCst);
+
+        if top > bottom {
+            inner.bottom.store(bottom + 1, Ordering::SeqCst);
+            return None;
+        }
+
+        let value = unsafe { (*buffer).slot(bottom).load(Ordering::SeqCst) };
+        if top == bottom {
+            //Thieves may be after the same element, the one that moves top gets it.
+            let won = inner.top.compare_exchange(top, top + 1, Ordering::SeqCst, Ordering::SeqCst).is_ok();
+            inner.bottom.store(bottom + 1, Ordering::SeqCst);
+            if !won {
+                return None;
+            }
+        }
+
+        Some(*unsafe { Box::from_raw(value) })
+    }
+
+    //Pops the first element like a thief would.
+    pub fn pop_front(&self) -> Option<T> {
+        steal(&self.inner)
+    }
+
+    //Buffers waiting to be freed, only the owner touches them.
+    #[cfg(all(test, feature = "std"))]
+    pub(crate) fn retired(&self) -> usize {
+        unsafe { (*self.inner.retired.get()).len() }
+    }
+
+    #[cfg(all(test, feature = "std"))]
+    pub(crate) fn capacity(&self) -> usize {
+        unsafe { (*self.inner.buffer.load(Ordering::SeqCst)).capacity() }
+    }
+}
+
+impl<T> Default for Worker<T> {
+    fn default() -> Self {
+        Worker::new()
+    }
+}
+
+impl<T> Stealer<T> {
+    //Only a guess while other threads are working.
+    pub fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    pub fn empty(&self) -> bool {
+        self.inner.size() == 0
+    }
+
+    /**
+    * @brief Pops the first element, the oldest one, from any thread.
+    *
+    * A failed attempt means another thread took an element, so it's retried.
+    *
+    * @return Option<T> None if the deque is empty.
+    */
+    pub fn pop_front(&self) -> Option<T> {
+        steal(&self.inner)
+    }
+}
+
+impl<T> Stealer<T> {
+    //Acts like a thief stopped inside pop_front until the returned function is called.
+    #[cfg(all(test, feature = "std"))]
+    pub(crate) fn hold_buffer(&self) -> impl FnOnce() + '_ {
+        let hazard = self.inner.acquire();
+        self.inner.protect(hazard);
+        move || hazard.release()
+    }
+}
+
+impl<T> ConcurrentDeque<T> for Worker<T> {
+    fn empty(&self) -> bool {
+        Worker::empty(self)
+    }
+
+    fn pop_front(&self) -> Option<T> {
+        Worker::pop_front(self)
+    }
+}
+
+impl<T> OwnedDeque<T> for Worker<T> {
+    fn push_back(&mut self, value : T) -> &mut Self {
+        Worker::push_back(self, value)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        Worker::pop_back(self)
+    }
+}
+
+impl<T> ConcurrentDeque<T> for Stealer<T> {
+    fn empty(&self) -> bool {
+        Stealer::empty(self)
+    }
+
+    fn pop_front(&self) -> Option<T> {
+        Stealer::pop_front(self)
+    }
+}
+
+impl<T> Clone for Stealer<T> {
+    fn clone(&self) -> Self {
+        Stealer { inner : self.inner.clone() }
+    }
+}
+
+fn steal<T>(inner : &Inner<T>) -> Option<T> {
+    let hazard = inner.acquire();
+
+    let result = loop {
+        let top = inner.top.load(Ordering::SeqCst);
+        let bottom = inner.bottom.load(Ordering::SeqCst);
+        if top >= bottom {
+            break None;
+        }
+
+        //The buffer has to be loaded after top, an older one may not hold the element at top.
+        let buffer = inner.protect(hazard);
+        let value = unsafe { (*buffer).slot(top).load(Ordering::SeqCst) };
+        if inner.top.compare_exchange(top, top + 1, Ordering::SeqCst, Ordering::SeqCst).is_ok() {
+            break Some(*unsafe { Box::from_raw(value) });
+        }
+    };
+
+    hazard.release();
+    result
+}
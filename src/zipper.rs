@@ -0,0 +1,136 @@
+use core::fmt;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+
+use crate::list::*;
+
+/**
+* Persistent cursor over a LinkedNode list.
+*
+* The elements before the cursor are kept in reverse order in prefix, the focused element is the
+* first one of suffix. Moving and editing at the cursor are O(1) and return a new zipper.
+*/
+#[derive(Debug, Clone)]
+pub struct ListZipper<T : Clone> {
+    prefix : Rc<LinkedNode<T>>,
+    suffix : Rc<LinkedNode<T>>,
+    index : usize,
+}
+
+//////////////////////////////////////////////////
+//Implementation part
+//////////////////////////////////////////////////
+
+impl<T : Clone> ListZipper<T> {
+    //The cursor starts at the first element.
+    pub fn new(list : Rc<LinkedNode<T>>) -> ListZipper<T> {
+        ListZipper {
+            prefix : LinkedNode::nil(), suffix : list, index : 0,
+        }
+    }
+
+    /**
+    * @brief Rebuilds the list with every edit.
+    *
+    * This method is implemented in O(index) for time and memory, the suffix is shared.
+    *
+    * @return Rc<LinkedNode<T>> The edited list.
+    */
+    pub fn to_list(&self) -> Rc<LinkedNode<T>> {
+        let mut values : Vec<T> = self.prefix.iter().cloned().collect();
+        values.reverse();
+        LinkedNode::prepend_all(values, &self.suffix)
+    }
+
+    //The element at the cursor, None if the cursor is past the last element.
+    pub fn focus(&self) -> Option<&T> {
+        self.suffix.head()
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn is_start(&self) -> bool {
+        self.prefix.is_empty()
+    }
+
+    pub fn is_end(&self) -> bool {
+        self.suffix.is_empty()
+    }
+
+    /**
+    * @brief Moves the cursor one element to the left.
+    *
+    * @return Option<Self> None if the cursor is already at the first element.
+    */
+    pub fn left(&self) -> Option<ListZipper<T>> {
+        let (value, prefix) = self.prefix.uncons()?;
+        Some(ListZipper {
+            suffix : LinkedNode::cons(LinkedNode::new(value.clone()), self.suffix.clone()),
+            prefix,
+            index : self.index - 1,
+        })
+    }
+
+    /**
+    * @brief Moves the cursor one element to the right.
+    *
+    * @return Option<Self> None if the cursor is already past the last element.
+    */
+    pub fn right(&self) -> Option<ListZipper<T>> {
+        let (value, suffix) = self.suffix.uncons()?;
+        Some(ListZipper {
+            prefix : LinkedNode::cons(LinkedNode::new(value.clone()), self.prefix.clone()),
+            suffix,
+            index : self.index + 1,
+        })
+    }
+
+    /**
+    * @brief Inserts an element before the focused one, the new element gets the focus.
+    *
+    * @return Self A new zipper with the inserted element.
+    */
+    pub fn insert(&self, value : T) -> ListZipper<T> {
+        ListZipper {
+            prefix : self.prefix.clone(),
+            suffix : LinkedNode::cons(LinkedNode::new(value), self.suffix.clone()),
+            index : self.index,
+        }
+    }
+
+    /**
+    * @brief Removes the focused element, the next one gets the focus.
+    *
+    * @return Option<Self> None if there is no focused element.
+    */
+    pub fn delete(&self) -> Option<ListZipper<T>> {
+        Some(ListZipper {
+            prefix : self.prefix.clone(),
+            suffix : self.suffix.tail()?,
+            index : self.index,
+        })
+    }
+
+    /**
+    * @brief Replaces the focused element.
+    *
+    * @return Option<Self> None if there is no focused element.
+    */
+    pub fn replace(&self, value : T) -> Option<ListZipper<T>> {
+        Some(ListZipper {
+            prefix : self.prefix.clone(),
+            suffix : LinkedNode::cons(LinkedNode::new(value), self.suffix.tail()?),
+            index : self.index,
+        })
+    }
+}
+
+impl<T> fmt::Display for ListZipper<T>
+    where T : fmt::Display + Clone
+{
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_list())
+    }
+}